@@ -0,0 +1,720 @@
+//! Type erased slices [`AnySlice`] and [`AnySliceMut`].
+//!
+//! Unlike [`AnyVec`] views, they do not depend on [`MemBuilder`] and `Traits`,
+//! so code that only reads or rearranges elements does not need to be generic.
+//! Element type described by [`ElementVTable`].
+//!
+//! Since `Traits` are not known, slices are neither [`Send`] nor [`Sync`].
+//!
+//! [`AnyVec`]: crate::AnyVec
+//! [`MemBuilder`]: crate::mem::MemBuilder
+
+use core::any::TypeId;
+use core::alloc::Layout;
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::ptr::NonNull;
+use core::slice::{from_raw_parts, from_raw_parts_mut};
+use crate::any_value::{AnyValue, AnyValueMut, AnyValueSizeless, AnyValueSizelessMut, AnyValueTypeless, AnyValueTypelessMut, DowncastError, Unknown};
+use crate::element_vtable::ElementVTable;
+use crate::traits::None;
+use crate::assert_types_equal;
+
+/// Borrowed type erased slice.
+///
+/// Obtained with [`AnyVec::slice`], or from `&[T]`.
+///
+/// ```rust
+/// # use any_vec::{AnySlice, AnyVec};
+/// fn sum(slice: AnySlice) -> u32 {
+///     slice.downcast_ref::<u32>().unwrap().iter().sum()
+/// }
+///
+/// let mut any_vec: AnyVec = AnyVec::new::<u32>();
+/// any_vec.downcast_mut::<u32>().unwrap().extend([1, 2, 3, 4]);
+/// assert_eq!(sum(any_vec.slice(1..3)), 5);
+/// assert_eq!(sum(AnySlice::from(&[1u32, 2][..])), 3);
+/// ```
+///
+/// [`AnyVec::slice`]: crate::AnyVec::slice
+pub struct AnySlice<'a>{
+    ptr: NonNull<u8>,
+    len: usize,
+    vtable: &'static ElementVTable,
+    phantom: PhantomData<&'a [u8]>
+}
+
+impl<'a> AnySlice<'a>{
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` valid elements, described by `vtable`,
+    /// borrowed for `'a`.
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: NonNull<u8>, len: usize, vtable: &'static ElementVTable) -> Self {
+        Self{ptr, len, vtable, phantom: PhantomData}
+    }
+
+    #[inline]
+    pub fn vtable(&self) -> &'static ElementVTable {
+        self.vtable
+    }
+
+    #[inline]
+    pub fn element_typeid(&self) -> TypeId {
+        self.vtable.type_id
+    }
+
+    #[inline]
+    pub fn element_type_name(&self) -> &'static str {
+        (self.vtable.type_name)()
+    }
+
+    #[inline]
+    pub fn element_layout(&self) -> Layout {
+        self.vtable.layout
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    unsafe fn element_ptr(&self, index: usize) -> NonNull<u8> {
+        NonNull::new_unchecked(self.ptr.as_ptr().add(index * self.vtable.layout.size()))
+    }
+
+    /// Return reference to element at `index` with bounds check.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if index is out of bounds.
+    #[inline]
+    pub fn at(&self, index: usize) -> SliceElementRef<'a>{
+        self.get(index).unwrap()
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<SliceElementRef<'a>>{
+        if index < self.len {
+            Some(unsafe{ self.get_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be in bounds.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: usize) -> SliceElementRef<'a>{
+        SliceElementRef(ManuallyDrop::new(SliceElement::new(self.element_ptr(index), self.vtable)))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> SliceIter<'a>{
+        SliceIter{ slice: *self, index: 0, end: self.len }
+    }
+
+    /// # Panics
+    ///
+    /// * Panics if `mid > len`.
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (AnySlice<'a>, AnySlice<'a>) {
+        assert!(mid <= self.len);
+        unsafe{(
+            Self::from_raw_parts(self.ptr, mid, self.vtable),
+            Self::from_raw_parts(self.element_ptr(mid), self.len - mid, self.vtable)
+        )}
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        unsafe{ from_raw_parts(self.ptr.as_ptr(), self.len * self.vtable.layout.size()) }
+    }
+
+    #[inline]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&'a [T]> {
+        if self.element_typeid() != TypeId::of::<T>() {
+            return None;
+        }
+        Some(unsafe{ from_raw_parts(self.ptr.as_ptr() as *const T, self.len) })
+    }
+
+    #[inline]
+    pub fn try_downcast_ref<T: 'static>(&self) -> Result<&'a [T], DowncastError> {
+        self.downcast_ref::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(self.element_type_name()))
+    }
+}
+
+impl<'a> Clone for AnySlice<'a>{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a> Copy for AnySlice<'a>{}
+
+impl<'a, T: 'static> From<&'a [T]> for AnySlice<'a>{
+    #[inline]
+    fn from(slice: &'a [T]) -> Self {
+        unsafe{ Self::from_raw_parts(
+            NonNull::from(slice).cast(), slice.len(), ElementVTable::of::<T, dyn None>()
+        ) }
+    }
+}
+
+impl<'a> From<AnySliceMut<'a>> for AnySlice<'a>{
+    #[inline]
+    fn from(slice: AnySliceMut<'a>) -> Self {
+        unsafe{ Self::from_raw_parts(slice.ptr, slice.len, slice.vtable) }
+    }
+}
+
+impl<'a> IntoIterator for AnySlice<'a>{
+    type Item = SliceElementRef<'a>;
+    type IntoIter = SliceIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Print elements, if [`ElementVTable::debug`] present. Only metadata otherwise.
+impl<'a> Debug for AnySlice<'a>{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.vtable.debug.is_some() {
+            f.debug_list().entries(self.iter()).finish()
+        } else {
+            f.debug_struct("AnySlice")
+             .field("typeid", &self.element_typeid())
+             .field("len", &self.len)
+             .finish()
+        }
+    }
+}
+
+/// Borrowed mutable type erased slice.
+///
+/// Obtained with [`AnyVec::slice_mut`], or from `&mut [T]`.
+///
+/// [`AnyVec::slice_mut`]: crate::AnyVec::slice_mut
+pub struct AnySliceMut<'a>{
+    ptr: NonNull<u8>,
+    len: usize,
+    vtable: &'static ElementVTable,
+    phantom: PhantomData<&'a mut [u8]>
+}
+
+impl<'a> AnySliceMut<'a>{
+    /// # Safety
+    ///
+    /// `ptr` must point to `len` valid elements, described by `vtable`,
+    /// exclusively borrowed for `'a`.
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: NonNull<u8>, len: usize, vtable: &'static ElementVTable) -> Self {
+        Self{ptr, len, vtable, phantom: PhantomData}
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> AnySlice<'_> {
+        unsafe{ AnySlice::from_raw_parts(self.ptr, self.len, self.vtable) }
+    }
+
+    /// Reborrow.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> AnySliceMut<'_> {
+        unsafe{ AnySliceMut::from_raw_parts(self.ptr, self.len, self.vtable) }
+    }
+
+    #[inline]
+    pub fn vtable(&self) -> &'static ElementVTable {
+        self.vtable
+    }
+
+    #[inline]
+    pub fn element_typeid(&self) -> TypeId {
+        self.vtable.type_id
+    }
+
+    #[inline]
+    pub fn element_type_name(&self) -> &'static str {
+        (self.vtable.type_name)()
+    }
+
+    #[inline]
+    pub fn element_layout(&self) -> Layout {
+        self.vtable.layout
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    unsafe fn element_ptr(&self, index: usize) -> NonNull<u8> {
+        NonNull::new_unchecked(self.ptr.as_ptr().add(index * self.vtable.layout.size()))
+    }
+
+    /// Return reference to element at `index` with bounds check.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if index is out of bounds.
+    #[inline]
+    pub fn at(&self, index: usize) -> SliceElementRef<'_>{
+        self.as_slice().at(index)
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<SliceElementRef<'_>>{
+        self.as_slice().get(index)
+    }
+
+    /// Return mutable reference to element at `index` with bounds check.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if index is out of bounds.
+    #[inline]
+    pub fn at_mut(&mut self, index: usize) -> SliceElementMut<'_>{
+        self.get_mut(index).unwrap()
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<SliceElementMut<'_>>{
+        if index < self.len {
+            Some(unsafe{ self.get_unchecked_mut(index) })
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be in bounds.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> SliceElementMut<'_>{
+        SliceElementMut(ManuallyDrop::new(SliceElement::new(self.element_ptr(index), self.vtable)))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> SliceIter<'_>{
+        self.as_slice().iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> SliceIterMut<'_>{
+        self.as_slice_mut().into_iter()
+    }
+
+    /// # Panics
+    ///
+    /// * Panics if `mid > len`.
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (AnySlice<'_>, AnySlice<'_>) {
+        self.as_slice().split_at(mid)
+    }
+
+    /// # Panics
+    ///
+    /// * Panics if `mid > len`.
+    #[inline]
+    pub fn split_at_mut(&mut self, mid: usize) -> (AnySliceMut<'_>, AnySliceMut<'_>) {
+        self.as_slice_mut().into_split_at(mid)
+    }
+
+    /// Same as [`split_at_mut`], but keep `'a`.
+    ///
+    /// [`split_at_mut`]: Self::split_at_mut
+    #[inline]
+    pub(crate) fn into_split_at(self, mid: usize) -> (AnySliceMut<'a>, AnySliceMut<'a>) {
+        assert!(mid <= self.len);
+        unsafe{(
+            AnySliceMut::from_raw_parts(self.ptr, mid, self.vtable),
+            AnySliceMut::from_raw_parts(self.element_ptr(mid), self.len - mid, self.vtable)
+        )}
+    }
+
+    /// Move out, leaving empty slice behind.
+    #[inline]
+    pub(crate) fn take(&mut self) -> AnySliceMut<'a> {
+        let len = mem::replace(&mut self.len, 0);
+        unsafe{ AnySliceMut::from_raw_parts(self.ptr, len, self.vtable) }
+    }
+
+    /// Swap two elements.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len && b < self.len);
+        if a != b {
+            unsafe{ ptr::swap_nonoverlapping(
+                self.element_ptr(a).as_ptr(),
+                self.element_ptr(b).as_ptr(),
+                self.vtable.layout.size()
+            ) }
+        }
+    }
+
+    /// Reverse the order of elements, in place.
+    pub fn reverse(&mut self) {
+        let size = self.vtable.layout.size();
+        if size == 0 {
+            return;
+        }
+        let half = self.len / 2;
+        for i in 0..half {
+            unsafe{ ptr::swap_nonoverlapping(
+                self.element_ptr(i).as_ptr(),
+                self.element_ptr(self.len - 1 - i).as_ptr(),
+                size
+            ) }
+        }
+    }
+
+    /// Rotate in-place, such that first `mid` elements move to the end.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `mid > len`.
+    #[inline]
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len);
+        let size = self.vtable.layout.size();
+        self.as_bytes_mut().rotate_left(mid * size);
+    }
+
+    /// Rotate in-place, such that last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `k > len`.
+    #[inline]
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len);
+        let size = self.vtable.layout.size();
+        self.as_bytes_mut().rotate_right(k * size);
+    }
+
+    /// Swap all elements with `other`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if types mismatch.
+    /// * Panics if lengths differ.
+    #[inline]
+    pub fn swap_with_slice(&mut self, other: &mut AnySliceMut) {
+        assert_types_equal(self.element_typeid(), other.element_typeid());
+        assert_eq!(self.len, other.len, "Slice length mismatch!");
+        self.as_bytes_mut().swap_with_slice(other.as_bytes_mut());
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_slice().as_bytes()
+    }
+
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe{ from_raw_parts_mut(self.ptr.as_ptr(), self.len * self.vtable.layout.size()) }
+    }
+
+    #[inline]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&[T]> {
+        self.as_slice().downcast_ref()
+    }
+
+    #[inline]
+    pub fn try_downcast_ref<T: 'static>(&self) -> Result<&[T], DowncastError> {
+        self.as_slice().try_downcast_ref()
+    }
+
+    #[inline]
+    pub fn downcast_mut<T: 'static>(&mut self) -> Option<&mut [T]> {
+        if self.element_typeid() != TypeId::of::<T>() {
+            return None;
+        }
+        Some(unsafe{ from_raw_parts_mut(self.ptr.as_ptr() as *mut T, self.len) })
+    }
+
+    #[inline]
+    pub fn try_downcast_mut<T: 'static>(&mut self) -> Result<&mut [T], DowncastError> {
+        let type_name = self.element_type_name();
+        self.downcast_mut::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(type_name))
+    }
+}
+
+impl<'a, T: 'static> From<&'a mut [T]> for AnySliceMut<'a>{
+    #[inline]
+    fn from(slice: &'a mut [T]) -> Self {
+        let len = slice.len();
+        unsafe{ Self::from_raw_parts(
+            NonNull::from(slice).cast(), len, ElementVTable::of::<T, dyn None>()
+        ) }
+    }
+}
+
+impl<'a> IntoIterator for AnySliceMut<'a>{
+    type Item = SliceElementMut<'a>;
+    type IntoIter = SliceIterMut<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let end = self.len;
+        SliceIterMut{ slice: self, index: 0, end }
+    }
+}
+
+impl<'a> Debug for AnySliceMut<'a>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+/// [`AnySlice`] element.
+///
+/// Accessible only through [`SliceElementRef`] and [`SliceElementMut`],
+/// so it can not be moved out.
+pub struct SliceElement<'a>{
+    ptr: NonNull<u8>,
+    vtable: &'static ElementVTable,
+    phantom: PhantomData<&'a mut u8>
+}
+
+impl<'a> SliceElement<'a>{
+    #[inline]
+    fn new(ptr: NonNull<u8>, vtable: &'static ElementVTable) -> Self {
+        Self{ptr, vtable, phantom: PhantomData}
+    }
+
+    /// Same as [`AnyValue::downcast_ref`], but return `&'a T`, instead of `&T`.
+    #[inline]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&'a T>{
+        if self.value_typeid() != TypeId::of::<T>(){
+            None
+        } else {
+            Some(unsafe{ &*(self.ptr.as_ptr() as *const T) })
+        }
+    }
+
+    /// Same as [`AnyValueMut::downcast_mut`], but return `&'a mut T`, instead of `&mut T`.
+    #[inline]
+    pub fn downcast_mut<T: 'static>(&mut self) -> Option<&'a mut T>{
+        if self.value_typeid() != TypeId::of::<T>(){
+            None
+        } else {
+            Some(unsafe{ &mut *(self.ptr.as_ptr() as *mut T) })
+        }
+    }
+}
+
+impl<'a> AnyValueSizeless for SliceElement<'a> {
+    type Type = Unknown;
+
+    #[inline]
+    fn as_bytes_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+}
+impl<'a> AnyValueSizelessMut for SliceElement<'a> {
+    #[inline]
+    fn as_bytes_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+}
+impl<'a> AnyValueTypeless for SliceElement<'a> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.vtable.layout.size()
+    }
+}
+impl<'a> AnyValue for SliceElement<'a> {
+    #[inline]
+    fn value_typeid(&self) -> TypeId {
+        self.vtable.type_id
+    }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        (self.vtable.type_name)()
+    }
+}
+impl<'a> AnyValueTypelessMut for SliceElement<'a> {}
+impl<'a> AnyValueMut for SliceElement<'a> {}
+
+/// Print value, if [`ElementVTable::debug`] present. Only type name otherwise.
+impl<'a> Debug for SliceElement<'a>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(debug_fn) = self.vtable.debug {
+            unsafe{ (debug_fn)(self.as_bytes_ptr(), f) }
+        } else {
+            f.write_str(self.value_type_name())
+        }
+    }
+}
+
+/// Reference to [`AnySlice`] element.
+pub struct SliceElementRef<'a>(ManuallyDrop<SliceElement<'a>>);
+impl<'a> Deref for SliceElementRef<'a>{
+    type Target = SliceElement<'a>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<'a> Clone for SliceElementRef<'a>{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(ManuallyDrop::new(SliceElement::new(self.ptr, self.vtable)))
+    }
+}
+impl<'a> Debug for SliceElementRef<'a>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Mutable reference to [`AnySliceMut`] element.
+pub struct SliceElementMut<'a>(ManuallyDrop<SliceElement<'a>>);
+impl<'a> Deref for SliceElementMut<'a>{
+    type Target = SliceElement<'a>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<'a> DerefMut for SliceElementMut<'a>{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<'a> Debug for SliceElementMut<'a>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// [`AnySlice`] iterator. Return [`SliceElementRef`] items.
+#[derive(Clone)]
+pub struct SliceIter<'a>{
+    slice: AnySlice<'a>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for SliceIter<'a>{
+    type Item = SliceElementRef<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            let element = unsafe{ self.slice.get_unchecked(self.index) };
+            self.index += 1;
+            Some(element)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.end - self.index;
+        (size, Some(size))
+    }
+}
+
+impl<'a> DoubleEndedIterator for SliceIter<'a>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end == self.index {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe{ self.slice.get_unchecked(self.end) })
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for SliceIter<'a>{}
+impl<'a> FusedIterator for SliceIter<'a>{}
+
+/// [`AnySliceMut`] iterator. Return [`SliceElementMut`] items.
+pub struct SliceIterMut<'a>{
+    slice: AnySliceMut<'a>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a> SliceIterMut<'a>{
+    #[inline]
+    unsafe fn element(&self, index: usize) -> SliceElementMut<'a> {
+        SliceElementMut(ManuallyDrop::new(SliceElement::new(self.slice.element_ptr(index), self.slice.vtable)))
+    }
+}
+
+impl<'a> Iterator for SliceIterMut<'a>{
+    type Item = SliceElementMut<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            let element = unsafe{ self.element(self.index) };
+            self.index += 1;
+            Some(element)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.end - self.index;
+        (size, Some(size))
+    }
+}
+
+impl<'a> DoubleEndedIterator for SliceIterMut<'a>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end == self.index {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe{ self.element(self.end) })
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for SliceIterMut<'a>{}
+impl<'a> FusedIterator for SliceIterMut<'a>{}
@@ -0,0 +1,272 @@
+extern crate alloc;
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+use alloc::boxed::Box;
+use core::alloc::Layout;
+use core::any::{type_name, Any, TypeId};
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::{fmt, mem, ptr};
+use crate::any_value::{AnyValue, AnyValueCloneable, AnyValueMut, AnyValueSizeless, AnyValueSizelessMut, AnyValueTypeless, AnyValueTypelessMut, Unknown};
+use crate::any_vec::traits::None;
+use crate::{copy_nonoverlapping_value, ElementVTable, SatisfyTraits};
+use crate::mem::dangling;
+use crate::traits::{Cloneable, Debuggable, Trait};
+
+enum Source{
+    Element(&'static ElementVTable),
+    /// From `Box<dyn Any>`. Points to the same value. With type name, if known.
+    Any(NonNull<dyn Any>, &'static str),
+}
+
+/// Owned heap allocated [`AnyValueMut`].
+///
+/// Unlike [`TempValue`], does not borrow [`AnyVec`]. Can be obtained from
+/// [`pop`]/[`remove`]/etc. result with [`TempValue::into_box`],
+/// or from `Box<T>` and `Box<dyn Any>`.
+///
+/// `Traits` work like in [`AnyVec`]. `AnyValueBox<dyn Cloneable>` is [`Clone`]
+/// and [`AnyValueCloneable`].
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::any_value::{AnyValue, AnyValueBox};
+/// let mut any_vec: AnyVec = AnyVec::new::<String>();
+/// any_vec.downcast_mut::<String>().unwrap().push(String::from("Hello"));
+///
+/// let value: AnyValueBox = any_vec.pop().unwrap().into_box();
+/// any_vec.push(value);
+///
+/// let value = any_vec.pop().unwrap().into_box();
+/// let value: Box<String> = value.downcast_box().ok().unwrap();
+/// assert_eq!(*value, "Hello");
+/// ```
+///
+/// [`TempValue`]: crate::ops::TempValue
+/// [`TempValue::into_box`]: crate::ops::TempValue::into_box
+/// [`AnyVec`]: crate::AnyVec
+/// [`pop`]: crate::AnyVec::pop
+/// [`remove`]: crate::AnyVec::remove
+pub struct AnyValueBox<Traits: ?Sized + Trait = dyn None>{
+    ptr: NonNull<u8>,
+    source: Source,
+    phantom: PhantomData<Traits>
+}
+
+impl<Traits: ?Sized + Trait> AnyValueBox<Traits>{
+    #[inline]
+    pub fn new<T: SatisfyTraits<Traits>>(value: T) -> Self {
+        Box::new(value).into()
+    }
+
+    /// Move value from `src` into new allocation.
+    ///
+    /// # Safety
+    ///
+    /// `src` must point to valid value of `vtable` type. `Traits` must be satisfied by `vtable`.
+    /// Value moved out, `src` must not be used after.
+    pub(crate) unsafe fn from_raw(src: *const u8, vtable: &'static ElementVTable) -> Self {
+        let this = Self::uninit(vtable);
+        ptr::copy_nonoverlapping(src, this.ptr.as_ptr(), vtable.layout.size());
+        this
+    }
+
+    /// Allocated, but not initialized. Must not be dropped.
+    #[inline]
+    unsafe fn uninit(vtable: &'static ElementVTable) -> Self {
+        let layout = vtable.layout;
+        let ptr = if layout.size() == 0 {
+            dangling(&layout)
+        } else {
+            NonNull::new(alloc(layout)).unwrap_or_else(|| handle_alloc_error(layout))
+        };
+        Self{ ptr, source: Source::Element(vtable), phantom: PhantomData }
+    }
+
+    #[inline]
+    fn layout(&self) -> Layout {
+        match self.source {
+            Source::Element(vtable) => vtable.layout,
+            Source::Any(any, _) => unsafe{ Layout::for_value(any.as_ref()) },
+        }
+    }
+
+    #[inline]
+    fn element_vtable(&self) -> &'static ElementVTable {
+        match self.source {
+            Source::Element(vtable) => vtable,
+            // `Box<dyn Any>` convertible only to `AnyValueBox` without traits.
+            Source::Any(..) => unreachable!(),
+        }
+    }
+
+    /// Free memory, without dropping value.
+    #[inline]
+    unsafe fn dealloc(&self){
+        let layout = self.layout();
+        if layout.size() != 0 {
+            dealloc(self.ptr.as_ptr(), layout);
+        }
+    }
+
+    /// Same as [`Box<dyn Any>::downcast`]. Memory reused as is.
+    ///
+    /// Return `self` back, if value type is not `T`.
+    #[inline]
+    pub fn downcast_box<T: 'static>(self) -> Result<Box<T>, Self> {
+        if self.value_typeid() != TypeId::of::<T>() {
+            return Err(self);
+        }
+        let ptr = self.ptr.as_ptr() as *mut T;
+        mem::forget(self);
+        Ok(unsafe{ Box::from_raw(ptr) })
+    }
+
+    /// Convert into `Box<dyn Any>`. Memory reused as is.
+    ///
+    /// Return `self` back, if value [`ElementVTable`] does not have `as_any` function.
+    #[inline]
+    pub fn into_any(self) -> Result<Box<dyn Any>, Self> {
+        let any = match self.source {
+            Source::Element(vtable) => match vtable.as_any {
+                Some(as_any) => as_any(self.ptr.as_ptr()),
+                Option::None => return Err(self),
+            },
+            Source::Any(any, _) => any.as_ptr(),
+        };
+        mem::forget(self);
+        Ok(unsafe{ Box::from_raw(any) })
+    }
+}
+
+impl<Traits: ?Sized + Trait, T: SatisfyTraits<Traits>> From<Box<T>> for AnyValueBox<Traits>{
+    #[inline]
+    fn from(value: Box<T>) -> Self {
+        Self{
+            ptr: unsafe{ NonNull::new_unchecked(Box::into_raw(value) as *mut u8) },
+            source: Source::Element(T::VTABLE),
+            phantom: PhantomData
+        }
+    }
+}
+
+impl AnyValueBox{
+    /// Same as `From<Box<dyn Any>>`, but with value type name.
+    ///
+    /// `Box<dyn Any>` does not know its value type name. Provide it here
+    /// for [`value_type_name`] and [`DowncastError`] diagnostic.
+    ///
+    /// [`value_type_name`]: AnyValue::value_type_name
+    /// [`DowncastError`]: crate::any_value::DowncastError
+    #[inline]
+    pub fn from_any(value: Box<dyn Any>, type_name: &'static str) -> Self {
+        let any = unsafe{ NonNull::new_unchecked(Box::into_raw(value)) };
+        Self{
+            ptr: any.cast::<u8>(),
+            source: Source::Any(any, type_name),
+            phantom: PhantomData
+        }
+    }
+}
+
+/// Value type name is not known - use [`AnyValueBox::from_any`] for better diagnostic.
+impl From<Box<dyn Any>> for AnyValueBox{
+    #[inline]
+    fn from(value: Box<dyn Any>) -> Self {
+        Self::from_any(value, type_name::<dyn Any>())
+    }
+}
+
+impl<Traits: ?Sized + Trait> AnyValueSizeless for AnyValueBox<Traits> {
+    type Type = Unknown;
+
+    #[inline]
+    fn as_bytes_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    unsafe fn move_into<KnownType:'static /*= Unknown*/>(self, out: *mut u8, bytes_size: usize) {
+        copy_nonoverlapping_value::<KnownType>(self.as_bytes_ptr(), out, bytes_size);
+        self.dealloc();
+        mem::forget(self);
+    }
+}
+impl<Traits: ?Sized + Trait> AnyValueSizelessMut for AnyValueBox<Traits> {
+    #[inline]
+    fn as_bytes_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+}
+impl<Traits: ?Sized + Trait> AnyValueTypeless for AnyValueBox<Traits> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.layout().size()
+    }
+}
+impl<Traits: ?Sized + Trait> AnyValue for AnyValueBox<Traits> {
+    #[inline]
+    fn value_typeid(&self) -> TypeId {
+        match self.source {
+            Source::Element(vtable) => vtable.type_id,
+            Source::Any(any, _) => unsafe{ any.as_ref() }.type_id(),
+        }
+    }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        match self.source {
+            Source::Element(vtable) => (vtable.type_name)(),
+            Source::Any(_, type_name) => type_name,
+        }
+    }
+}
+impl<Traits: ?Sized + Trait> AnyValueTypelessMut for AnyValueBox<Traits> {}
+impl<Traits: ?Sized + Trait> AnyValueMut for AnyValueBox<Traits> {}
+
+impl<Traits: ?Sized + Cloneable + Trait> AnyValueCloneable for AnyValueBox<Traits> {
+    #[inline]
+    unsafe fn clone_into(&self, out: *mut u8) {
+        let clone_fn = self.element_vtable().clone.unwrap();
+        (clone_fn)(self.as_bytes_ptr(), out, 1);
+    }
+}
+
+impl<Traits: ?Sized + Cloneable + Trait> Clone for AnyValueBox<Traits>{
+    fn clone(&self) -> Self {
+        unsafe{
+            let mut cloned = Self::uninit(self.element_vtable());
+            self.clone_into(cloned.as_bytes_mut_ptr());
+            cloned
+        }
+    }
+}
+
+impl<Traits: ?Sized + Debuggable + Trait> Debug for AnyValueBox<Traits>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let debug_fn = self.element_vtable().debug.unwrap();
+        unsafe{ (debug_fn)(self.as_bytes_ptr(), f) }
+    }
+}
+
+impl<Traits: ?Sized + Trait> Drop for AnyValueBox<Traits>{
+    #[inline]
+    fn drop(&mut self) {
+        match self.source {
+            Source::Element(vtable) => unsafe{
+                if let Some(drop_fn) = vtable.drop {
+                    (drop_fn)(self.ptr.as_ptr(), 1);
+                }
+                self.dealloc();
+            },
+            Source::Any(any, _) => unsafe{
+                drop(Box::from_raw(any.as_ptr()));
+            }
+        }
+    }
+}
+
+unsafe impl<Traits: ?Sized + Send + Trait> Send for AnyValueBox<Traits>{}
+unsafe impl<Traits: ?Sized + Sync + Trait> Sync for AnyValueBox<Traits>{}
@@ -0,0 +1,210 @@
+use core::alloc::Layout;
+use core::any::TypeId;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::{fmt, ptr};
+use crate::any_value::{AnyValue, AnyValueCloneable, AnyValueMut, AnyValueSizeless, AnyValueSizelessMut, AnyValueTypeless, AnyValueTypelessMut, Unknown};
+use crate::any_vec::traits::None;
+use crate::{ElementVTable, SatisfyTraits};
+use crate::traits::{Cloneable, Debuggable, Trait};
+
+/// [`InlineStorage`] alignment.
+pub(crate) const INLINE_ALIGN: usize = 16;
+
+/// `SIZE` bytes, aligned to [`INLINE_ALIGN`].
+///
+/// Shared by [`AnyValueInline`] and [`SmallHeap`].
+///
+/// [`SmallHeap`]: crate::mem::SmallHeap
+#[repr(C, align(16))]
+pub(crate) struct InlineStorage<const SIZE: usize>(MaybeUninit<[u8; SIZE]>);
+
+impl<const SIZE: usize> InlineStorage<SIZE>{
+    #[inline]
+    pub(crate) const fn uninit() -> Self {
+        Self(MaybeUninit::uninit())
+    }
+
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr() as *const u8
+    }
+
+    #[inline]
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_mut_ptr() as *mut u8
+    }
+}
+
+/// Owned [`AnyValueMut`], stored inline, without heap.
+///
+/// Holds value up to `SIZE` bytes, with alignment up to 16.
+/// Unlike [`TempValue`], does not borrow [`AnyVec`]. Can be obtained from
+/// [`pop`]/[`remove`]/etc. result with [`TempValue::into_inline`].
+///
+/// `Traits` work like in [`AnyVec`]. `AnyValueInline<SIZE, dyn Cloneable>` is [`Clone`]
+/// and [`AnyValueCloneable`].
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::any_value::{AnyValue, AnyValueInline};
+/// # use any_vec::mem::Stack;
+/// # use any_vec::traits::None;
+/// let mut any_vec: AnyVec<dyn None, Stack<64>> = AnyVec::new::<u64>();
+/// any_vec.downcast_mut::<u64>().unwrap().push(42);
+///
+/// let value: AnyValueInline<8> = any_vec.pop().unwrap().into_inline().ok().unwrap();
+/// any_vec.push(value);
+/// assert_eq!(any_vec.downcast_ref::<u64>().unwrap().as_slice(), &[42]);
+/// ```
+///
+/// [`TempValue`]: crate::ops::TempValue
+/// [`TempValue::into_inline`]: crate::ops::TempValue::into_inline
+/// [`AnyVec`]: crate::AnyVec
+/// [`pop`]: crate::AnyVec::pop
+/// [`remove`]: crate::AnyVec::remove
+pub struct AnyValueInline<const SIZE: usize, Traits: ?Sized + Trait = dyn None>{
+    storage: InlineStorage<SIZE>,
+    vtable: &'static ElementVTable,
+    phantom: PhantomData<(*mut u8, Traits)>
+}
+
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValueInline<SIZE, Traits>{
+    /// Storage alignment.
+    pub const ALIGN: usize = INLINE_ALIGN;
+
+    /// Can value of `layout` be stored.
+    #[inline]
+    pub const fn fits(layout: Layout) -> bool {
+        layout.size() <= SIZE && layout.align() <= INLINE_ALIGN
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `T` does not [`fit`].
+    ///
+    /// [`fit`]: Self::fits
+    #[inline]
+    pub fn new<T: SatisfyTraits<Traits>>(value: T) -> Self {
+        match Self::try_new(value) {
+            Ok(this) => this,
+            Err(_) => panic!("Value does not fit!"),
+        }
+    }
+
+    /// Return `value` back, if `T` does not [`fit`].
+    ///
+    /// [`fit`]: Self::fits
+    #[inline]
+    pub fn try_new<T: SatisfyTraits<Traits>>(value: T) -> Result<Self, T> {
+        if !Self::fits(Layout::new::<T>()) {
+            return Err(value);
+        }
+        let mut this = Self::uninit(T::VTABLE);
+        unsafe{ ptr::write(this.as_bytes_mut_ptr() as *mut T, value); }
+        Ok(this)
+    }
+
+    /// Move `value` in, with [`AnyValueSizeless::move_into`].
+    ///
+    /// Return `value` back, if it does not [`fit`].
+    ///
+    /// # Safety
+    ///
+    /// `vtable` must describe `value` type. `Traits` must be satisfied by `vtable`.
+    ///
+    /// [`fit`]: Self::fits
+    #[inline]
+    pub unsafe fn try_from_any_value<V: AnyValue>(value: V, vtable: &'static ElementVTable)
+        -> Result<Self, V>
+    {
+        debug_assert_eq!(value.value_typeid(), vtable.type_id);
+        if !Self::fits(vtable.layout) {
+            return Err(value);
+        }
+        let mut this = Self::uninit(vtable);
+        value.move_into::<V::Type>(this.as_bytes_mut_ptr(), vtable.layout.size());
+        Ok(this)
+    }
+
+    /// Not initialized. Must not be dropped.
+    #[inline]
+    fn uninit(vtable: &'static ElementVTable) -> Self {
+        Self{
+            storage: InlineStorage::uninit(),
+            vtable,
+            phantom: PhantomData
+        }
+    }
+}
+
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValueSizeless for AnyValueInline<SIZE, Traits> {
+    type Type = Unknown;
+
+    #[inline]
+    fn as_bytes_ptr(&self) -> *const u8 {
+        self.storage.as_ptr()
+    }
+}
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValueSizelessMut for AnyValueInline<SIZE, Traits> {
+    #[inline]
+    fn as_bytes_mut_ptr(&mut self) -> *mut u8 {
+        self.storage.as_mut_ptr()
+    }
+}
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValueTypeless for AnyValueInline<SIZE, Traits> {
+    #[inline]
+    fn size(&self) -> usize {
+        self.vtable.layout.size()
+    }
+}
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValue for AnyValueInline<SIZE, Traits> {
+    #[inline]
+    fn value_typeid(&self) -> TypeId {
+        self.vtable.type_id
+    }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        (self.vtable.type_name)()
+    }
+}
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValueTypelessMut for AnyValueInline<SIZE, Traits> {}
+impl<const SIZE: usize, Traits: ?Sized + Trait> AnyValueMut for AnyValueInline<SIZE, Traits> {}
+
+impl<const SIZE: usize, Traits: ?Sized + Cloneable + Trait> AnyValueCloneable for AnyValueInline<SIZE, Traits> {
+    #[inline]
+    unsafe fn clone_into(&self, out: *mut u8) {
+        let clone_fn = self.vtable.clone.unwrap();
+        (clone_fn)(self.as_bytes_ptr(), out, 1);
+    }
+}
+
+impl<const SIZE: usize, Traits: ?Sized + Cloneable + Trait> Clone for AnyValueInline<SIZE, Traits>{
+    fn clone(&self) -> Self {
+        let mut cloned = Self::uninit(self.vtable);
+        unsafe{ self.clone_into(cloned.as_bytes_mut_ptr()); }
+        cloned
+    }
+}
+
+impl<const SIZE: usize, Traits: ?Sized + Debuggable + Trait> Debug for AnyValueInline<SIZE, Traits>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let debug_fn = self.vtable.debug.unwrap();
+        unsafe{ (debug_fn)(self.as_bytes_ptr(), f) }
+    }
+}
+
+impl<const SIZE: usize, Traits: ?Sized + Trait> Drop for AnyValueInline<SIZE, Traits>{
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(drop_fn) = self.vtable.drop {
+            unsafe{ (drop_fn)(self.as_bytes_mut_ptr(), 1); }
+        }
+    }
+}
+
+unsafe impl<const SIZE: usize, Traits: ?Sized + Send + Trait> Send for AnyValueInline<SIZE, Traits>{}
+unsafe impl<const SIZE: usize, Traits: ?Sized + Sync + Trait> Sync for AnyValueInline<SIZE, Traits>{}
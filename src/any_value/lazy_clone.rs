@@ -48,6 +48,11 @@ impl<'a, T: AnyValueCloneable + AnyValue> AnyValue for LazyClone<'a, T>{
     fn value_typeid(&self) -> TypeId {
         self.value.value_typeid()
     }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        self.value.value_type_name()
+    }
 }
 
 impl<'a, T: AnyValueCloneable> AnyValueCloneable for LazyClone<'a, T>{
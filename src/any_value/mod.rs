@@ -23,13 +23,21 @@
 mod wrapper;
 mod raw;
 mod lazy_clone;
+pub(crate) mod inline;
+#[cfg(feature="alloc")]
+mod boxed;
 
 pub use lazy_clone::LazyClone;
+pub use inline::AnyValueInline;
+#[cfg(feature="alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use boxed::AnyValueBox;
 pub use wrapper::AnyValueWrapper;
 pub use raw::{AnyValueRaw, AnyValueSizelessRaw, AnyValueTypelessRaw};
 
-use core::any::TypeId;
-use core::{mem, ptr, slice};
+use core::any::{type_name, TypeId};
+use core::{fmt, mem, ptr, slice};
+use core::fmt::{Display, Formatter};
 use core::mem::{MaybeUninit, size_of};
 
 /// Marker for unknown type.
@@ -41,6 +49,33 @@ impl Unknown {
     }
 }
 
+/// Type mismatch error of `try_downcast`-family.
+///
+/// Names both types, for diagnostic.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DowncastError{
+    /// Requested type name.
+    pub expected: &'static str,
+    /// Actual type name.
+    pub actual: &'static str,
+}
+
+impl DowncastError{
+    #[inline]
+    pub(crate) fn new<T: 'static>(actual: &'static str) -> Self {
+        Self{ expected: type_name::<T>(), actual }
+    }
+}
+
+impl Display for DowncastError{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "can not downcast {} to {}", self.actual, self.expected)
+    }
+}
+
+impl core::error::Error for DowncastError{}
+
 /// Prelude for traits.
 pub mod traits{
     pub use super::{AnyValueSizeless, AnyValueSizelessMut};
@@ -114,6 +149,17 @@ pub trait AnyValueTypeless: AnyValueSizeless {
 pub trait AnyValue: AnyValueTypeless {
     fn value_typeid(&self) -> TypeId;
 
+    /// Value type name. Diagnostic only.
+    ///
+    /// Default implementation returns [`Self::Type`] name, which
+    /// is [`Unknown`] for type-erased values.
+    ///
+    /// [`Self::Type`]: AnyValueSizeless::Type
+    #[inline]
+    fn value_type_name(&self) -> &'static str{
+        type_name::<Self::Type>()
+    }
+
     #[inline]
     fn downcast_ref<T: 'static>(&self) -> Option<&T>{
         if self.value_typeid() != TypeId::of::<T>(){
@@ -123,6 +169,15 @@ pub trait AnyValue: AnyValueTypeless {
         }
     }
 
+    /// Same as [`downcast_ref`], but return [`DowncastError`] on type mismatch.
+    ///
+    /// [`downcast_ref`]: Self::downcast_ref
+    #[inline]
+    fn try_downcast_ref<T: 'static>(&self) -> Result<&T, DowncastError>{
+        self.downcast_ref::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(self.value_type_name()))
+    }
+
     #[inline]
     fn downcast<T: 'static>(self) -> Option<T>
         where Self: Sized
@@ -133,6 +188,20 @@ pub trait AnyValue: AnyValueTypeless {
             Some(unsafe{ self.downcast_unchecked::<T>() })
         }
     }
+
+    /// Same as [`downcast`], but return [`DowncastError`] on type mismatch.
+    ///
+    /// [`downcast`]: Self::downcast
+    #[inline]
+    fn try_downcast<T: 'static>(self) -> Result<T, DowncastError>
+        where Self: Sized
+    {
+        if self.value_typeid() != TypeId::of::<T>(){
+            Err(DowncastError::new::<T>(self.value_type_name()))
+        } else {
+            Ok(unsafe{ self.downcast_unchecked::<T>() })
+        }
+    }
 }
 
 /// Mutable [AnyValueSizeless].
@@ -192,6 +261,18 @@ pub trait AnyValueMut: AnyValueTypelessMut + AnyValue {
         }
     }
 
+    /// Same as [`downcast_mut`], but return [`DowncastError`] on type mismatch.
+    ///
+    /// [`downcast_mut`]: Self::downcast_mut
+    #[inline(always)]
+    fn try_downcast_mut<T: 'static>(&mut self) -> Result<&mut T, DowncastError>{
+        if self.value_typeid() != TypeId::of::<T>(){
+            Err(DowncastError::new::<T>(self.value_type_name()))
+        } else {
+            Ok(unsafe{ self.downcast_mut_unchecked::<T>() })
+        }
+    }
+
     /// Swaps underlying values.
     ///
     /// # Panic
@@ -1,7 +1,8 @@
-use core::any::TypeId;
+use core::any::{type_name, TypeId};
 use core::ptr::NonNull;
 use crate::any_value::{AnyValue, AnyValueMut, AnyValueTypelessMut, AnyValueTypeless, AnyValueSizeless, AnyValueSizelessMut};
 use crate::any_value::Unknown;
+use crate::ElementVTable;
 
 /// [AnyValueSizeless] non-owning byte ptr wrapper, that knows nothing about it's type.
 /// 
@@ -146,15 +147,36 @@ impl AnyValueTypelessMut for AnyValueTypelessRaw {}
 /// [AnyVec]: crate::AnyVec
 pub struct AnyValueRaw {
     raw_unsafe: AnyValueTypelessRaw,
-    typeid: TypeId
+    typeid: TypeId,
+    type_name: fn() -> &'static str,
 }
 
 impl AnyValueRaw {
+    /// Type name is not known, [`value_type_name`] will return [`Unknown`] name.
+    /// Use [`from_vtable`] for better diagnostic.
+    ///
+    /// [`value_type_name`]: AnyValue::value_type_name
+    /// [`from_vtable`]: Self::from_vtable
     #[inline]
     pub unsafe fn new(ptr: NonNull<u8>, size: usize, typeid: TypeId) -> Self{
         Self{
             raw_unsafe: AnyValueTypelessRaw::new(ptr, size),
-            typeid
+            typeid,
+            type_name: type_name::<Unknown>
+        }
+    }
+
+    /// Value of `vtable` type.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to valid value of `vtable` type.
+    #[inline]
+    pub unsafe fn from_vtable(ptr: NonNull<u8>, vtable: &'static ElementVTable) -> Self{
+        Self{
+            raw_unsafe: AnyValueTypelessRaw::new(ptr, vtable.layout.size()),
+            typeid: vtable.type_id,
+            type_name: vtable.type_name
         }
     }
 }
@@ -184,6 +206,11 @@ impl AnyValue for AnyValueRaw {
     fn value_typeid(&self) -> TypeId {
         self.typeid
     }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
 }
 
 impl AnyValueTypelessMut for AnyValueRaw {}
@@ -1,27 +1,41 @@
+#[cfg(feature="alloc")]
+extern crate alloc;
+
+#[cfg(feature="alloc")]
+use alloc::vec::Vec;
 use core::alloc::Layout;
 use core::any::TypeId;
+use core::cmp::Ordering;
 use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::mem::{ManuallyDrop, MaybeUninit};
 use core::ops::{Deref, DerefMut, Range, RangeBounds};
 use core::ptr::NonNull;
-use core::{fmt, ptr, slice};
+use core::{cmp, fmt, ptr, slice};
 use core::slice::{from_raw_parts, from_raw_parts_mut};
-use crate::{AnyVecTyped, into_range, mem, ops, assert_types_equal};
-use crate::any_value::{AnyValue, AnyValueSizeless, Unknown};
+use crate::{AnySlice, AnySliceMut, AnyVecTyped, into_range, mem, ops, assert_types_equal};
+use crate::any_value::{AnyValue, AnyValueCloneable, AnyValueSizeless, DowncastError, Unknown};
 use crate::any_vec_raw::{AnyVecRaw, DropFn};
+use crate::element_vtable::ElementVTable;
 use crate::ops::{TempValue, Remove, SwapRemove, remove, swap_remove, Pop, pop};
 use crate::ops::{Drain, Splice, drain, splice};
 use crate::any_vec::traits::{None};
-use crate::clone_type::{CloneFn, CloneFnTrait, CloneType};
+use crate::clone_type::{CloneFn, CmpFn, DebugFn, EqFn, HashFn};
 use crate::element::{ElementPointer, ElementMut, ElementRef};
 use crate::any_vec_ptr::AnyVecPtr;
 use crate::iter::{Iter, IterMut, IterRef};
-use crate::mem::{Mem, MemBuilder, MemBuilderSizeable, MemRawParts, MemResizable};
-use crate::traits::{Cloneable, Trait};
+use crate::chunks::{Chunks, ChunksExact, ChunksMut, Windows};
+use crate::mem::{Mem, MemBuilder, MemBuilderSizeable, MemRawParts, MemResizable, TryInsertError, TryReserveError};
+use crate::traits::{Cloneable, Comparable, Debuggable, FnTableFor, Hashable, Ordered, Trait};
 
 /// Trait constraints.
-/// Possible variants [`Cloneable`], [`Send`] and [`Sync`], in any combination.
+/// Possible variants [`Cloneable`], [`Debuggable`], [`CloneableDebuggable`], [`Comparable`],
+/// [`Hashable`] or [`Ordered`], each of them with any combination of [`Send`] and [`Sync`].
+///
+/// N.B. Only one of non-auto traits ([`Cloneable`], [`Debuggable`], [`Comparable`], [`Hashable`],
+/// [`Ordered`], `Serializable`) can be used in trait object. So `dyn Cloneable + Debuggable`
+/// is not possible - use [`CloneableDebuggable`] instead.
 ///
 /// # Example
 /// ```rust
@@ -31,20 +45,86 @@ use crate::traits::{Cloneable, Trait};
 /// let v2 = v1.clone();
 ///
 /// ```
+///
+/// # Custom markers
+///
+/// Marker is a trait object type, which implements [`Trait`] with function table,
+/// and [`FnTableFor<T>`] for each suitable `T`. Function table stored in [`AnyVec`],
+/// and accessible with [`AnyVec::fn_table`].
+///
+/// Custom marker can also combine built-in ones: implement [`Cloneable`], [`Debuggable`], etc.
+/// for it, and provide their functions in [`FnTableFor`] (`CLONE_FN`, `DEBUG_FN`, etc.).
+///
+/// ```rust
+/// use any_vec::AnyVec;
+/// use any_vec::any_value::AnyValueSizeless;
+/// use any_vec::traits::{FnTableFor, Trait};
+///
+/// pub trait Numeric{}
+///
+/// pub type ToF64Fn = unsafe fn(*const u8) -> f64;
+/// unsafe fn to_f64<T: Copy + Into<f64>>(ptr: *const u8) -> f64 {
+///     (*(ptr as *const T)).into()
+/// }
+///
+/// impl Trait for dyn Numeric + Send{
+///     type FnTable = ToF64Fn;
+/// }
+/// unsafe impl<T: Copy + Into<f64> + Send> FnTableFor<T> for dyn Numeric + Send{
+///     const FN_TABLE: ToF64Fn = to_f64::<T>;
+/// }
+///
+/// let mut vec: AnyVec<dyn Numeric + Send> = AnyVec::new::<u32>();
+/// vec.downcast_mut::<u32>().unwrap().extend([1, 2, 3]);
+///
+/// let to_f64 = *vec.fn_table();
+/// let sum: f64 = vec.iter().map(|e| unsafe{ to_f64(e.as_bytes_ptr()) }).sum();
+/// assert_eq!(sum, 6.0);
+/// ```
+///
+/// [`CloneableDebuggable`]: traits::CloneableDebuggable
+/// [`AnyVec`]: crate::AnyVec
+/// [`AnyVec::fn_table`]: crate::AnyVec::fn_table
 pub mod traits{
+    pub use crate::clone_type::{CloneFn, CmpFn, DebugFn, EqFn, HashFn};
+
     // TODO: rename to TraitConstraints or Constraints?
     /// [`AnyVec`]s trait constraints.
     ///
+    /// Implemented by marker trait object type. Defines function table, stored in
+    /// [`AnyVec`].
+    ///
+    /// See [module-level documentation](self) for custom marker example.
+    ///
     /// [`AnyVec`]: crate::AnyVec
-    pub trait Trait: 'static + crate::clone_type::CloneType{}
-    impl Trait for dyn None {}
-    impl Trait for dyn Sync{}
-    impl Trait for dyn Send{}
-    impl Trait for dyn Sync + Send{}
-    impl Trait for dyn Cloneable{}
-    impl Trait for dyn Cloneable + Send{}
-    impl Trait for dyn Cloneable + Sync{}
-    impl Trait for dyn Cloneable + Send+ Sync{}
+    pub trait Trait: 'static{
+        /// Per-type function table. Use ZST, if there is nothing to store.
+        type FnTable: Copy + 'static;
+    }
+
+    /// Function table of `T` for marker `Self`.
+    ///
+    /// Implementation for `T` means that `T` satisfy `Self` constraints. See [`SatisfyTraits`].
+    ///
+    /// Built-in functions go to [`ElementVTable`]. They are used only if marker
+    /// also implements corresponding built-in marker trait ([`Cloneable`], [`Debuggable`], etc.).
+    ///
+    /// # Safety
+    ///
+    /// * All functions must work with `T`.
+    /// * `T` must be [`Send`]/[`Sync`], if `Self` is.
+    ///
+    /// [`SatisfyTraits`]: crate::SatisfyTraits
+    /// [`ElementVTable`]: crate::ElementVTable
+    pub unsafe trait FnTableFor<T>: Trait{
+        const FN_TABLE: Self::FnTable;
+
+        const CLONE_FN: Option<CloneFn> = None;
+        const DEBUG_FN: Option<DebugFn> = None;
+        const EQ_FN: Option<EqFn> = None;
+        const HASH_FN: Option<HashFn> = None;
+        const CMP_FN: Option<CmpFn> = None;
+    }
 
     /// Does not enforce anything. Default.
     pub trait None {}
@@ -55,10 +135,77 @@ pub mod traits{
 
     /// Enforce type [`Clone`]-ability.
     pub trait Cloneable{}
+
+    /// Enforce type [`Debug`]-ability.
+    ///
+    /// Makes [`AnyVec`] and its elements print actual values with [`Debug`].
+    ///
+    /// [`Debug`]: core::fmt::Debug
+    /// [`AnyVec`]: crate::AnyVec
+    pub trait Debuggable{}
+
+    /// [`Cloneable`] and [`Debuggable`] together.
+    ///
+    /// ```rust
+    /// # use any_vec::AnyVec;
+    /// # use any_vec::traits::*;
+    /// let mut v1: AnyVec<dyn CloneableDebuggable + Send> = AnyVec::new::<usize>();
+    /// v1.downcast_mut::<usize>().unwrap().push(1);
+    /// let v2 = v1.clone();
+    /// assert_eq!(format!("{v2:?}"), "[1]");
+    /// ```
+    pub trait CloneableDebuggable: Cloneable + Debuggable{}
+
+    /// Enforce type [`PartialEq`]-ability.
+    ///
+    /// Makes [`AnyVec`] and its elements comparable with `==`.
+    ///
+    /// Only [`PartialEq`] is required, so non-[`Eq`] types like `f64` can be stored.
+    /// Thus `AnyVec<dyn Comparable>` is [`PartialEq`], but not [`Eq`].
+    /// Use [`Hashable`] for [`Eq`].
+    ///
+    /// [`AnyVec`]: crate::AnyVec
+    pub trait Comparable{}
+
+    /// Enforce type [`Hash`] + [`Eq`]-ability.
+    ///
+    /// Makes [`AnyVec`] and its elements [`Hash`] and [`Eq`], so they
+    /// can be used as `HashMap`/`HashSet` keys. Implies [`Comparable`].
+    ///
+    /// [`Hash`]: core::hash::Hash
+    /// [`AnyVec`]: crate::AnyVec
+    pub trait Hashable: Comparable{}
+
+    /// Enforce type [`Ord`]-ability.
+    ///
+    /// Enables type erased [`AnyVec::sort`], [`AnyVec::binary_search`] and co.
+    /// Makes [`AnyVec`] elements comparable with `<`. Implies [`Comparable`].
+    ///
+    /// [`AnyVec`]: crate::AnyVec
+    /// [`AnyVec::sort`]: crate::AnyVec::sort
+    /// [`AnyVec::binary_search`]: crate::AnyVec::binary_search
+    pub trait Ordered: Comparable{}
+
+    #[cfg(feature="serde")]
+    pub use crate::serialize::SerializeFn;
+
+    /// Enforce type [`Serialize`]-ability.
+    ///
+    /// Makes [`AnyVec`] [`Serialize`], as sequence of elements.
+    /// Use [`TypeRegistry`] to deserialize it.
+    ///
+    /// [`Serialize`]: serde::Serialize
+    /// [`AnyVec`]: crate::AnyVec
+    /// [`TypeRegistry`]: crate::TypeRegistry
+    #[cfg(feature="serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub trait Serializable{}
 }
 
 /// Trait for compile time check - does `T` satisfy `Traits` constraints.
 ///
+/// Implemented for all `T`, for which `Traits` implements [`FnTableFor<T>`].
+///
 /// Almost for sure you don't need to use it. It is public - just in case.
 /// In our tests we found niche case where it was needed:
 /// ```rust
@@ -80,22 +227,26 @@ pub mod traits{
 ///         }
 ///     # }
 /// ```
-pub trait SatisfyTraits<Traits: ?Sized>: CloneFnTrait<Traits> {}
-impl<T> SatisfyTraits<dyn None> for T{}
-impl<T: Clone> SatisfyTraits<dyn Cloneable> for T{}
-impl<T: Send> SatisfyTraits<dyn Send> for T{}
-impl<T: Sync> SatisfyTraits<dyn Sync> for T{}
-impl<T: Send + Sync> SatisfyTraits<dyn Send + Sync> for T{}
-impl<T: Clone + Send> SatisfyTraits<dyn Cloneable + Send> for T{}
-impl<T: Clone + Sync> SatisfyTraits<dyn Cloneable + Sync> for T{}
-impl<T: Clone + Send + Sync> SatisfyTraits<dyn Cloneable + Send + Sync> for T{}
+///
+/// [`FnTableFor<T>`]: traits::FnTableFor
+pub trait SatisfyTraits<Traits: ?Sized + Trait>{
+    /// `Traits` function table for `Self`.
+    const FN_TABLE: Traits::FnTable;
+
+    /// [`ElementVTable`] of `Self` with `Traits` functions.
+    const VTABLE: &'static ElementVTable;
+}
+impl<T: 'static, Traits: ?Sized + FnTableFor<T>> SatisfyTraits<Traits> for T{
+    const FN_TABLE: Traits::FnTable = <Traits as FnTableFor<T>>::FN_TABLE;
+    const VTABLE: &'static ElementVTable = &ElementVTable::new::<T, Traits>();
+}
 
 /// [`AnyVec`] raw parts.
 ///
 /// You can get it with [`AnyVec::into_raw_parts`], or build/edit
 /// it manually. And with [`AnyVec::from_raw_parts`], you can construct
 /// [`AnyVec`].
-pub struct RawParts<M: MemBuilder/* = mem::Default*/>
+pub struct RawParts<M: MemBuilder/* = mem::Default*/, Traits: ?Sized + Trait = dyn None>
 where
     M::Mem: MemRawParts
 {
@@ -103,15 +254,13 @@ where
     pub mem_handle: <M::Mem as MemRawParts>::Handle,
     pub capacity:       usize,
     pub len:            usize,
-    pub element_layout: Layout,
-    pub element_typeid: TypeId,
-    pub element_drop:   Option<DropFn>,
+    pub element_vtable: &'static ElementVTable,
 
-    /// Ignored if non Cloneable.
-    pub element_clone:  CloneFn,
+    /// `Traits` function table. See [`FnTableFor`].
+    pub fn_table:       Traits::FnTable,
 }
 
-impl<M: MemBuilder> Clone for RawParts<M>
+impl<M: MemBuilder, Traits: ?Sized + Trait> Clone for RawParts<M, Traits>
 where
     M::Mem: MemRawParts,
     <M::Mem as MemRawParts>::Handle: Clone
@@ -123,10 +272,8 @@ where
             mem_handle: self.mem_handle.clone(),
             capacity: self.capacity,
             len: self.capacity,
-            element_layout: self.element_layout,
-            element_typeid: self.element_typeid,
-            element_drop: self.element_drop,
-            element_clone: self.element_clone,
+            element_vtable: self.element_vtable,
+            fn_table: self.fn_table,
         }
     }
 }
@@ -148,7 +295,7 @@ where
 pub struct AnyVec<Traits: ?Sized + Trait = dyn None, M: MemBuilder = mem::Default>
 {
     pub(crate) raw: AnyVecRaw<M>,
-    clone_fn: <Traits as CloneType>::Type,  // ZST if Traits: !Cloneable
+    fn_table: Traits::FnTable,   // ZST for markers without functions
     phantom: PhantomData<Traits>
 }
 
@@ -156,10 +303,9 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
 {
     #[inline]
     fn build<T: SatisfyTraits<Traits>>(raw: AnyVecRaw<M>) -> Self {
-        let clone_fn = <T as CloneFnTrait<Traits>>::CLONE_FN;
         Self{
             raw,
-            clone_fn: <Traits as CloneType>::new(clone_fn),
+            fn_table: <T as SatisfyTraits<Traits>>::FN_TABLE,
             phantom: PhantomData
         }
     }
@@ -190,10 +336,52 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         where T: SatisfyTraits<Traits>
     {
         let mem = mem_builder.build(Layout::new::<T>());
-        let raw = AnyVecRaw::new::<T>(mem_builder, mem);
+        let raw = AnyVecRaw::new(mem_builder, mem, T::VTABLE);
         Self::build::<T>(raw)
     }
 
+    /// Constructs empty [`AnyVec`] with elements described by `vtable`,
+    /// using [`Default`] [`MemBuilder`].
+    ///
+    /// Allows to work with types, known only at runtime.
+    ///
+    /// # Safety
+    ///
+    /// `vtable` must correctly describe element type. `Traits` validity not checked:
+    /// element type must be [`Send`]/[`Sync`], if `Traits` is.
+    /// Operations with functions, missing in `vtable`, will panic.
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_vtable(vtable: &'static ElementVTable) -> Self
+    where
+        Traits::FnTable: Default,
+        M: Default
+    {
+        Self::from_vtable_in(vtable, Default::default())
+    }
+
+    /// Constructs empty [`AnyVec`] with elements described by `vtable`,
+    /// using provided `mem_builder`.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`from_vtable`].
+    ///
+    /// [`from_vtable`]: Self::from_vtable
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_vtable_in(vtable: &'static ElementVTable, mut mem_builder: M) -> Self
+    where
+        Traits::FnTable: Default
+    {
+        let mem = mem_builder.build(vtable.layout);
+        Self{
+            raw: AnyVecRaw::new(mem_builder, mem, vtable),
+            fn_table: Default::default(),
+            phantom: PhantomData
+        }
+    }
+
     /// Constructs empty [`AnyVec`] with specified capacity and
     /// elements of type `T`, using [`Default`] [`MemBuilder`].
     ///
@@ -227,14 +415,14 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         M: MemBuilderSizeable
     {
         let mem = mem_builder.build_with_size(Layout::new::<T>(), capacity);
-        let raw = AnyVecRaw::new::<T>(mem_builder, mem);
+        let raw = AnyVecRaw::new(mem_builder, mem, T::VTABLE);
         Self::build::<T>(raw)
     }
 
     /// Destructure `AnyVec` into [`RawParts`].
     #[inline]
     #[must_use]
-    pub fn into_raw_parts(self) -> RawParts<M>
+    pub fn into_raw_parts(self) -> RawParts<M, Traits>
     where
         M::Mem: MemRawParts
     {
@@ -242,16 +430,14 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
 
         let mem_builder = unsafe{ ptr::read(&this.raw.mem_builder) };
         let mem = unsafe{ ptr::read(&this.raw.mem) };
-        let (mem_handle, element_layout, capacity) = mem.into_raw_parts();
+        let (mem_handle, _, capacity) = mem.into_raw_parts();
         RawParts{
             mem_builder,
             mem_handle,
             capacity,
             len: this.raw.len,
-            element_layout,
-            element_typeid: this.raw.type_id,
-            element_drop: this.raw.drop_fn,
-            element_clone: this.clone_fn()
+            element_vtable: this.raw.vtable,
+            fn_table: this.fn_table,
         }
     }
 
@@ -261,9 +447,7 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     ///
     /// ## Traits
     ///
-    /// Traits validity not checked. `RawParts` of underlying type must implement Traits.
-    /// It is not safe to opt-in [`Cloneable`], if initial `AnyVec` was not constructed with
-    /// that trait.
+    /// Traits validity not checked. `RawParts` function table must belong to underlying type.
     ///
     /// ## RawParts
     ///
@@ -271,7 +455,7 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     ///
     #[inline]
     #[must_use]
-    pub unsafe fn from_raw_parts(raw_parts: RawParts<M>) -> Self
+    pub unsafe fn from_raw_parts(raw_parts: RawParts<M, Traits>) -> Self
     where
         M::Mem: MemRawParts
     {
@@ -280,14 +464,13 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
                 mem_builder: raw_parts.mem_builder,
                 mem: MemRawParts::from_raw_parts(
                     raw_parts.mem_handle,
-                    raw_parts.element_layout,
+                    raw_parts.element_vtable.layout,
                     raw_parts.capacity
                 ),
                 len: raw_parts.len,
-                type_id: raw_parts.element_typeid,
-                drop_fn: raw_parts.element_drop
+                vtable: raw_parts.element_vtable
             },
-            clone_fn: <Traits as CloneType>::new(raw_parts.element_clone),
+            fn_table: raw_parts.fn_table,
             phantom: PhantomData
         }
     }
@@ -301,7 +484,7 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     pub fn clone_empty(&self) -> Self {
         Self {
             raw: self.raw.clone_empty(),
-            clone_fn: self.clone_fn,
+            fn_table: self.fn_table,
             phantom: PhantomData
         }
     }
@@ -329,14 +512,48 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     pub fn clone_empty_in<NewM: MemBuilder>(&self, mem_builder: NewM) -> AnyVec<Traits, NewM> {
         AnyVec {
             raw: self.raw.clone_empty_in(mem_builder),
-            clone_fn: self.clone_fn,
+            fn_table: self.fn_table,
             phantom: PhantomData
         }
     }
 
+    /// `Traits` function table of elements type.
+    ///
+    /// See [`FnTableFor`].
+    #[inline]
+    pub fn fn_table(&self) -> &Traits::FnTable {
+        &self.fn_table
+    }
+
+    /// Element type description.
+    #[inline]
+    pub fn vtable(&self) -> &'static ElementVTable {
+        self.raw.vtable
+    }
+
+    #[inline]
+    pub(crate) fn clone_fn(&self) -> Option<CloneFn>{
+        self.raw.vtable.clone
+    }
+
+    #[inline]
+    pub(crate) fn debug_fn(&self) -> Option<DebugFn>{
+        self.raw.vtable.debug
+    }
+
+    #[inline]
+    pub(crate) fn eq_fn(&self) -> Option<EqFn>{
+        self.raw.vtable.eq
+    }
+
     #[inline]
-    pub(crate) fn clone_fn(&self) -> CloneFn{
-        <Traits as CloneType>::get(self.clone_fn)
+    pub(crate) fn hash_fn(&self) -> Option<HashFn>{
+        self.raw.vtable.hash
+    }
+
+    #[inline]
+    pub(crate) fn cmp_fn(&self) -> Option<CmpFn>{
+        self.raw.vtable.cmp
     }
 
     /// Reserves capacity for at least `additional` more elements to be inserted
@@ -381,6 +598,29 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         self.raw.reserve_exact(additional)
     }
 
+    /// Same as [`reserve`], but return [`TryReserveError`] instead of panic.
+    ///
+    /// Available for all [`Mem`]s. Fixed capacity [`Mem`] return
+    /// [`TryReserveError::CapacityExceeded`], if out of capacity.
+    ///
+    /// [`reserve`]: Self::reserve
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.raw.try_reserve(additional)
+    }
+
+    /// Same as [`reserve_exact`], but return [`TryReserveError`] instead of panic.
+    ///
+    /// Not available, if provided [`MemBuilder::Mem`] is not [`MemResizable`].
+    ///
+    /// [`reserve_exact`]: Self::reserve_exact
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError>
+        where M::Mem: MemResizable
+    {
+        self.raw.try_reserve_exact(additional)
+    }
+
     /// Shrinks the capacity as much as possible.
     /// Exact behavior defined by implementation of [`MemResizable`].
     ///
@@ -431,6 +671,16 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         }
     }
 
+    /// Same as [`downcast_ref`], but return [`DowncastError`] naming
+    /// both types, if container does not hold elements of type T.
+    ///
+    /// [`downcast_ref`]: Self::downcast_ref
+    #[inline]
+    pub fn try_downcast_ref<T: 'static>(&self) -> Result<AnyVecRef<'_, T, M>, DowncastError> {
+        self.downcast_ref::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(self.element_type_name()))
+    }
+
     /// Returns [`AnyVecRef`] - typed view to const AnyVec.
     ///
     /// # Safety
@@ -453,6 +703,17 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         }
     }
 
+    /// Same as [`downcast_mut`], but return [`DowncastError`] naming
+    /// both types, if container does not hold elements of type T.
+    ///
+    /// [`downcast_mut`]: Self::downcast_mut
+    #[inline]
+    pub fn try_downcast_mut<T: 'static>(&mut self) -> Result<AnyVecMut<'_, T, M>, DowncastError> {
+        let type_name = self.element_type_name();
+        self.downcast_mut::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(type_name))
+    }
+
     /// Returns [`AnyVecMut`] - typed view to mut AnyVec.
     ///
     /// # Safety
@@ -499,6 +760,87 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         Iter::new(AnyVecPtr::from(self), 0, len)
     }
 
+    /// [`AnySlice`] of elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if range is out of bounds.
+    #[inline]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> AnySlice<'_>{
+        let Range{start, end} = into_range(self.len(), range);
+        unsafe{ AnySlice::from_raw_parts(
+            NonNull::new_unchecked(self.raw.get_unchecked(start) as *mut u8),
+            end - start,
+            self.raw.vtable
+        ) }
+    }
+
+    /// [`AnySliceMut`] of elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if range is out of bounds.
+    #[inline]
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> AnySliceMut<'_>{
+        let Range{start, end} = into_range(self.len(), range);
+        unsafe{ AnySliceMut::from_raw_parts(
+            NonNull::new_unchecked(self.raw.get_unchecked_mut(start)),
+            end - start,
+            self.raw.vtable
+        ) }
+    }
+
+    /// Iterate over [`AnySlice`]s of `chunk_size` elements. Last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `chunk_size` is 0.
+    #[inline]
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_>{
+        Chunks::new(self.slice(..), chunk_size)
+    }
+
+    /// Iterate over [`AnySliceMut`]s of `chunk_size` elements. Last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `chunk_size` is 0.
+    #[inline]
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_>{
+        ChunksMut::new(self.slice_mut(..), chunk_size)
+    }
+
+    /// Iterate over [`AnySlice`]s of exactly `chunk_size` elements.
+    /// Rest is accessible with [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `chunk_size` is 0.
+    #[inline]
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<'_>{
+        ChunksExact::new(self.slice(..), chunk_size)
+    }
+
+    /// Iterate over all overlapping [`AnySlice`]s of `size` elements.
+    ///
+    /// ```rust
+    /// # use any_vec::AnyVec;
+    /// let mut any_vec: AnyVec = AnyVec::new::<u32>();
+    /// any_vec.downcast_mut::<u32>().unwrap().extend([1, 2, 3]);
+    /// let mut windows = any_vec.windows(2);
+    /// assert_eq!(windows.next().unwrap().downcast_ref::<u32>().unwrap(), &[1, 2]);
+    /// assert_eq!(windows.next().unwrap().downcast_ref::<u32>().unwrap(), &[2, 3]);
+    /// assert!(windows.next().is_none());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `size` is 0.
+    #[inline]
+    pub fn windows(&self, size: usize) -> Windows<'_>{
+        Windows::new(self.slice(..), size)
+    }
+
     /// Return reference to element at `index` with bounds check.
     ///
     /// # Panics
@@ -589,6 +931,28 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         self.raw.insert_unchecked(index, value);
     }
 
+    /// Same as [`insert`], but return value back with [`TryInsertError`],
+    /// if out of memory.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if type mismatch.
+    /// * Panics if index is out of bounds.
+    ///
+    /// [`insert`]: Self::insert
+    #[inline]
+    pub fn try_insert<V: AnyValue>(&mut self, index: usize, value: V) -> Result<(), TryInsertError<V>> {
+        self.raw.type_check(&value);
+        assert!(index <= self.len(), "Index out of range!");
+        if let Err(error) = self.raw.try_reserve_one(){
+            return Err(TryInsertError{error, value});
+        }
+        unsafe{
+            self.raw.insert_unchecked(index, value);
+        }
+        Ok(())
+    }
+
     /// # Panics
     ///
     /// * Panics if type mismatch.
@@ -617,6 +981,44 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         self.raw.push_unchecked(value);
     }
 
+    /// Same as [`push`], but return value back with [`TryInsertError`],
+    /// if out of memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if type mismatch.
+    ///
+    /// [`push`]: Self::push
+    #[inline]
+    pub fn try_push<V: AnyValue>(&mut self, value: V) -> Result<(), TryInsertError<V>> {
+        self.raw.type_check(&value);
+        if let Err(error) = self.raw.try_reserve_one(){
+            return Err(TryInsertError{error, value});
+        }
+        unsafe{
+            self.raw.push_unchecked(value);
+        }
+        Ok(())
+    }
+
+    /// Fallible [`Extend::extend`]. Push elements one by one, until out of memory.
+    ///
+    /// On failure, return value which could not be pushed. Already pushed
+    /// elements stay in container. Rest of `iter` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if type mismatch.
+    pub fn try_extend<V: AnyValue, I: IntoIterator<Item=V>>(&mut self, iter: I) -> Result<(), TryInsertError<V>> {
+        let iter = iter.into_iter();
+        // size_hint may lie, just try to reserve upfront.
+        let _ = self.raw.try_reserve(iter.size_hint().0);
+        for v in iter {
+            self.try_push(v)?;
+        }
+        Ok(())
+    }
+
     /// # Leaking
     ///
     /// If the returned [`TempValue`] goes out of scope without being dropped (due to
@@ -695,6 +1097,102 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         }
     }
 
+    /// Swap two elements.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.slice_mut(..).swap(a, b)
+    }
+
+    /// Reverse the order of elements, in place.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.slice_mut(..).reverse()
+    }
+
+    /// Rotate in-place, such that first `mid` elements move to the end.
+    ///
+    /// Works on bytes, without element type knowledge.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `mid > len`.
+    #[inline]
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.slice_mut(..).rotate_left(mid)
+    }
+
+    /// Rotate in-place, such that last `k` elements move to the front.
+    ///
+    /// Works on bytes, without element type knowledge.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `k > len`.
+    #[inline]
+    pub fn rotate_right(&mut self, k: usize) {
+        self.slice_mut(..).rotate_right(k)
+    }
+
+    /// Swap all elements with `other`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if types mismatch.
+    /// * Panics if lengths differ.
+    #[inline]
+    pub fn swap_with_slice(&mut self, other: &mut AnySliceMut) {
+        self.slice_mut(..).swap_with_slice(other)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    /// Discarded elements destructed with [`element_drop`].
+    ///
+    /// If `f` or element destructor panics - already discarded elements
+    /// stay discarded, the rest are kept.
+    ///
+    /// [`element_drop`]: Self::element_drop
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(ElementRef<'_, Traits, M>) -> bool
+    {
+        let any_vec_ptr = AnyVecPtr::from(self);
+        ops::retain::retain(any_vec_ptr, |element| f(
+            ElementRef(ManuallyDrop::new(ElementPointer::new(
+                any_vec_ptr,
+                unsafe{ NonNull::new_unchecked(element) }
+            )))
+        ));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable
+    /// reference to it.
+    ///
+    /// Same as [`retain`], but allows to mutate elements.
+    ///
+    /// [`retain`]: Self::retain
+    #[inline]
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(ElementMut<'_, Traits, M>) -> bool
+    {
+        let any_vec_ptr = AnyVecPtr::from(self);
+        ops::retain::retain(any_vec_ptr, |element| f(
+            ElementMut(ManuallyDrop::new(ElementPointer::new(
+                any_vec_ptr,
+                unsafe{ NonNull::new_unchecked(element) }
+            )))
+        ));
+    }
+
     /// Removes the specified range from the vector in bulk, returning all removed
     /// elements as an iterator. If the iterator is dropped before being fully consumed,
     /// it drops the remaining removed elements.
@@ -761,6 +1259,342 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
         ))
     }
 
+    /// Shortens the vector, keeping the first `len` elements and dropping
+    /// the rest.
+    ///
+    /// If `len` is greater or equal to the vector's current length, this has
+    /// no effect. Has no effect on the allocated capacity of the vector.
+    #[inline]
+    pub fn truncate(&mut self, len: usize){
+        self.raw.truncate(len)
+    }
+
+    /// Splits the collection into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the elements in the range
+    /// `[at, len)`. After the call, the original vector will be left containing
+    /// the elements `[0, at)` with its previous capacity unchanged.
+    ///
+    /// Returned vector constructed with [`clone_empty`], so it have the same
+    /// `Traits` and (cloned) [`MemBuilder`].
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `at > len`.
+    /// * Panics if out of memory.
+    ///
+    /// [`clone_empty`]: Self::clone_empty
+    #[inline]
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        Self{
+            raw: self.raw.split_off(at),
+            fn_table: self.fn_table,
+            phantom: PhantomData
+        }
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the
+    /// difference, with each additional slot filled with the result of
+    /// calling the closure `f`. If `new_len` is less than `len`, the vector
+    /// is simply truncated.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if type mismatch.
+    /// * Panics if out of memory.
+    pub fn resize_with<F, V>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> V,
+        V: AnyValue
+    {
+        let len = self.len();
+        if new_len > len {
+            self.raw.reserve(new_len - len);
+            for _ in len..new_len {
+                self.push(f());
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the
+    /// difference, with each additional slot filled with clone of `value`.
+    /// If `new_len` is less than `len`, the vector is simply truncated.
+    ///
+    /// Clones in bulk: `value` cloned once, then already filled range doubled
+    /// with [`element_clone`].
+    ///
+    /// # Panics
+    ///
+    /// * Panics if type mismatch.
+    /// * Panics if out of memory.
+    ///
+    /// [`element_clone`]: Self::element_clone
+    pub fn resize<V>(&mut self, new_len: usize, value: &V)
+    where
+        Traits: Cloneable,
+        V: AnyValue + AnyValueCloneable
+    {
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+            return;
+        }
+        self.raw.type_check(value);
+        self.raw.reserve(new_len - len);
+
+        let clone_fn = self.element_clone();
+        let size = self.raw.element_layout().size();
+        unsafe{
+            // Source and destination from the same pointer - memory may be inside `self`.
+            let ptr = self.raw.mem.as_mut_ptr();
+            value.clone_into(ptr.add(size * len));
+            self.raw.len = len + 1;
+
+            // Set len after each step. If `clone_fn` panics, it drops
+            // its own partial result - and we keep all previous steps.
+            while self.raw.len < new_len {
+                let filled = self.raw.len - len;
+                let count = cmp::min(filled, new_len - self.raw.len);
+                (clone_fn)(ptr.add(size * len), ptr.add(size * self.raw.len), count);
+                self.raw.len += count;
+            }
+        }
+    }
+
+    /// Clones elements from `range` to the end of the vector.
+    ///
+    /// Whole range cloned with one [`element_clone`] call.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if range is out of bounds.
+    /// * Panics if out of memory.
+    ///
+    /// [`element_clone`]: Self::element_clone
+    pub fn extend_from_within(&mut self, range: impl RangeBounds<usize>)
+    where
+        Traits: Cloneable
+    {
+        let Range{start, end} = into_range(self.len(), range);
+        let count = end - start;
+        self.raw.reserve(count);
+
+        let clone_fn = self.element_clone();
+        let size = self.raw.element_layout().size();
+        let len = self.len();
+        unsafe{
+            // Source and destination from the same pointer - memory may be inside `self`.
+            // If `clone_fn` panics, it drops its own partial result - len stays untouched.
+            let ptr = self.raw.mem.as_mut_ptr();
+            (clone_fn)(ptr.add(size * start), ptr.add(size * len), count);
+        }
+        self.raw.len = len + count;
+    }
+
+    /// Clones all elements of `slice` to the end of the vector.
+    ///
+    /// Whole slice cloned with one [`element_clone`] call.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if type mismatch.
+    /// * Panics if out of memory.
+    ///
+    /// [`element_clone`]: Self::element_clone
+    pub fn extend_from_slice(&mut self, slice: &AnySlice)
+    where
+        Traits: Cloneable
+    {
+        assert_types_equal(slice.element_typeid(), self.element_typeid());
+        let count = slice.len();
+        self.raw.reserve(count);
+
+        let clone_fn = self.element_clone();
+        let len = self.len();
+        unsafe{
+            (clone_fn)(slice.as_bytes().as_ptr(), self.raw.get_unchecked_mut(len), count);
+        }
+        self.raw.len = len + count;
+    }
+
+    /// Sorts the vector with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and does not
+    /// allocate. Elements are permuted in place with byte swaps.
+    ///
+    /// If `compare` panics - vector contains all of its elements, in unspecified order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(ElementRef<'_, Traits, M>, ElementRef<'_, Traits, M>) -> Ordering
+    {
+        let any_vec_ptr = AnyVecPtr::from(&mut *self);
+        let size = self.element_layout().size();
+        unsafe{
+            ops::sort::sort(self.raw.mem.as_mut_ptr(), size, self.len(), |l, r| compare(
+                ElementRef(ManuallyDrop::new(ElementPointer::new(
+                    any_vec_ptr, NonNull::new_unchecked(l as *mut u8)
+                ))),
+                ElementRef(ManuallyDrop::new(ElementPointer::new(
+                    any_vec_ptr, NonNull::new_unchecked(r as *mut u8)
+                ))),
+            ));
+        }
+    }
+
+    /// Sorts the vector with a key extraction function.
+    ///
+    /// Stable. See [`sort_by`].
+    ///
+    /// [`sort_by`]: Self::sort_by
+    #[inline]
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(ElementRef<'_, Traits, M>) -> K
+    {
+        self.sort_by(|l, r| f(l).cmp(&f(r)))
+    }
+
+    /// Sorts the vector with a comparator function, but might not preserve
+    /// the order of equal elements.
+    ///
+    /// Does not allocate. See [`sort_by`].
+    ///
+    /// [`sort_by`]: Self::sort_by
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(ElementRef<'_, Traits, M>, ElementRef<'_, Traits, M>) -> Ordering
+    {
+        let any_vec_ptr = AnyVecPtr::from(&mut *self);
+        let size = self.element_layout().size();
+        unsafe{
+            ops::sort::sort_unstable(self.raw.mem.as_mut_ptr(), size, self.len(), |l, r| compare(
+                ElementRef(ManuallyDrop::new(ElementPointer::new(
+                    any_vec_ptr, NonNull::new_unchecked(l as *mut u8)
+                ))),
+                ElementRef(ManuallyDrop::new(ElementPointer::new(
+                    any_vec_ptr, NonNull::new_unchecked(r as *mut u8)
+                ))),
+            ));
+        }
+    }
+
+    /// Sorts the vector with [`element_cmp`].
+    ///
+    /// Stable. Does not allocate. See [`sort_by`].
+    ///
+    /// [`element_cmp`]: Self::element_cmp
+    /// [`sort_by`]: Self::sort_by
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        Traits: Ordered
+    {
+        let cmp_fn = self.element_cmp();
+        let size = self.element_layout().size();
+        unsafe{
+            ops::sort::sort(self.raw.mem.as_mut_ptr(), size, self.len(), |l, r| (cmp_fn)(l, r));
+        }
+    }
+
+    /// Sorts the vector with [`element_cmp`], but might not preserve
+    /// the order of equal elements.
+    ///
+    /// Does not allocate.
+    ///
+    /// [`element_cmp`]: Self::element_cmp
+    #[inline]
+    pub fn sort_unstable(&mut self)
+    where
+        Traits: Ordered
+    {
+        let cmp_fn = self.element_cmp();
+        let size = self.element_layout().size();
+        unsafe{
+            ops::sort::sort_unstable(self.raw.mem.as_mut_ptr(), size, self.len(), |l, r| (cmp_fn)(l, r));
+        }
+    }
+
+    /// Checks if the elements of this vector are sorted.
+    pub fn is_sorted(&self) -> bool
+    where
+        Traits: Ordered
+    {
+        let cmp_fn = self.element_cmp();
+        (1..self.len()).all(|i| unsafe{
+            (cmp_fn)(self.raw.get_unchecked(i-1), self.raw.get_unchecked(i)) != Ordering::Greater
+        })
+    }
+
+    /// Binary searches this sorted vector for a given element.
+    ///
+    /// Same as [`slice::binary_search`]: if the value is found then [`Ok`] is returned,
+    /// containing the index of the matching element. If there are multiple matches,
+    /// then any one of the matches could be returned. If the value is not found
+    /// then [`Err`] is returned, containing the index where a matching element
+    /// could be inserted while maintaining sorted order.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if type mismatch.
+    pub fn binary_search<V: AnyValue>(&self, value: &V) -> Result<usize, usize>
+    where
+        Traits: Ordered
+    {
+        self.raw.type_check(value);
+        let cmp_fn = self.element_cmp();
+        let value_ptr = value.as_bytes_ptr();
+
+        let mut left = 0;
+        let mut right = self.len();
+        while left < right {
+            let mid = left + (right - left) / 2;
+            match unsafe{ (cmp_fn)(self.raw.get_unchecked(mid), value_ptr) } {
+                Ordering::Less    => left = mid + 1,
+                Ordering::Greater => right = mid,
+                Ordering::Equal   => return Ok(mid),
+            }
+        }
+        Err(left)
+    }
+
+    /// Returns the minimum element. If several elements are equally minimum,
+    /// the first element is returned.
+    ///
+    /// Returns [`None`] if vector is empty.
+    pub fn min(&self) -> Option<ElementRef<'_, Traits, M>>
+    where
+        Traits: Ordered
+    {
+        let cmp_fn = self.element_cmp();
+        let min = (1..self.len()).fold(0, |min, i| unsafe{
+            if (cmp_fn)(self.raw.get_unchecked(i), self.raw.get_unchecked(min)) == Ordering::Less { i } else { min }
+        });
+        self.get(min)
+    }
+
+    /// Returns the maximum element. If several elements are equally maximum,
+    /// the last element is returned.
+    ///
+    /// Returns [`None`] if vector is empty.
+    pub fn max(&self) -> Option<ElementRef<'_, Traits, M>>
+    where
+        Traits: Ordered
+    {
+        let cmp_fn = self.element_cmp();
+        let max = (1..self.len()).fold(0, |max, i| unsafe{
+            if (cmp_fn)(self.raw.get_unchecked(i), self.raw.get_unchecked(max)) != Ordering::Less { i } else { max }
+        });
+        self.get(max)
+    }
+
     #[inline]
     pub fn clear(&mut self){
         self.raw.clear()
@@ -769,7 +1603,13 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     /// Element TypeId
     #[inline]
     pub fn element_typeid(&self) -> TypeId{
-        self.raw.type_id
+        self.raw.vtable.type_id
+    }
+
+    /// Element type name. Diagnostic only.
+    #[inline]
+    pub fn element_type_name(&self) -> &'static str{
+        (self.raw.vtable.type_name)()
     }
 
     /// Element Layout
@@ -784,7 +1624,7 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     /// None - drop is not needed.
     #[inline]
     pub fn element_drop(&self) -> Option<DropFn> {
-        self.raw.drop_fn
+        self.raw.vtable.drop
     }
 
     /// Element clone function.
@@ -795,7 +1635,49 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     where
         Traits: Cloneable
     {
-        self.clone_fn()
+        self.clone_fn().unwrap()
+    }
+
+    /// Element debug format function.
+    #[inline]
+    pub fn element_debug(&self) -> DebugFn
+    where
+        Traits: Debuggable
+    {
+        self.debug_fn().unwrap()
+    }
+
+    /// Element equality function.
+    ///
+    /// Compare two elements of the same type.
+    #[inline]
+    pub fn element_eq(&self) -> EqFn
+    where
+        Traits: Comparable
+    {
+        self.eq_fn().unwrap()
+    }
+
+    /// Element hash function.
+    ///
+    /// Feed element into [`Hasher`].
+    #[inline]
+    pub fn element_hash(&self) -> HashFn
+    where
+        Traits: Hashable
+    {
+        self.hash_fn().unwrap()
+    }
+
+    /// Element compare function.
+    ///
+    /// Compare two elements of the same type.
+    #[inline]
+    pub fn element_cmp(&self) -> CmpFn
+    where
+        Traits: Ordered
+    {
+        self.cmp_fn().unwrap()
     }
 
     #[inline]
@@ -814,6 +1696,49 @@ impl<Traits: ?Sized + Trait, M: MemBuilder> AnyVec<Traits, M>
     }
 }
 
+#[cfg(feature="alloc")]
+impl<Traits: ?Sized + Trait> AnyVec<Traits, mem::Heap>
+{
+    /// Constructs `AnyVec` from [`Vec`], without copying elements.
+    /// Buffer reused as is.
+    #[inline]
+    #[must_use]
+    pub fn from_vec<T>(vec: Vec<T>) -> Self
+    where
+        T: 'static + SatisfyTraits<Traits>
+    {
+        let mut vec = ManuallyDrop::new(vec);
+        let raw_parts = RawParts{
+            mem_builder: mem::Heap,
+            mem_handle: unsafe{ NonNull::new_unchecked(vec.as_mut_ptr() as *mut u8) },
+            capacity: vec.capacity(),
+            len: vec.len(),
+            element_vtable: T::VTABLE,
+            fn_table: T::FN_TABLE,
+        };
+        unsafe{ Self::from_raw_parts(raw_parts) }
+    }
+
+    /// Converts `AnyVec` into [`Vec<T>`], without copying elements.
+    /// Buffer reused as is.
+    ///
+    /// Return `self` back, if element type is not `T`.
+    #[inline]
+    pub fn into_vec<T: 'static>(self) -> Result<Vec<T>, Self> {
+        if self.element_typeid() != TypeId::of::<T>() {
+            return Err(self);
+        }
+        let raw_parts = self.into_raw_parts();
+        Ok(unsafe{
+            Vec::from_raw_parts(
+                raw_parts.mem_handle.as_ptr() as *mut T,
+                raw_parts.len,
+                raw_parts.capacity
+            )
+        })
+    }
+}
+
 unsafe impl<Traits: ?Sized + Send + Trait, M: MemBuilder + Send> Send for AnyVec<Traits, M>
     where M::Mem: Send
 {}
@@ -824,13 +1749,45 @@ impl<Traits: ?Sized + Cloneable + Trait, M: MemBuilder> Clone for AnyVec<Traits,
 {
     fn clone(&self) -> Self {
         Self{
-            raw: unsafe{ self.raw.clone(self.clone_fn()) },
-            clone_fn: self.clone_fn,
+            raw: unsafe{ self.raw.clone(self.element_clone()) },
+            fn_table: self.fn_table,
             phantom: PhantomData
         }
     }
 }
 
+/// Element-wise comparison. Vectors with different element types are never equal.
+impl<Traits, M, OtherM> PartialEq<AnyVec<Traits, OtherM>> for AnyVec<Traits, M>
+where
+    Traits: ?Sized + Comparable + Trait,
+    M: MemBuilder,
+    OtherM: MemBuilder
+{
+    fn eq(&self, other: &AnyVec<Traits, OtherM>) -> bool {
+        if self.element_typeid() != other.element_typeid() || self.len() != other.len() {
+            return false;
+        }
+        let eq_fn = self.element_eq();
+        (0..self.len()).all(|i| unsafe{
+            (eq_fn)(self.raw.get_unchecked(i), other.raw.get_unchecked(i))
+        })
+    }
+}
+
+/// [`Hashable`] elements are [`Eq`]. [`Comparable`] ones may be only [`PartialEq`].
+impl<Traits: ?Sized + Hashable + Trait, M: MemBuilder> Eq for AnyVec<Traits, M>{}
+
+/// Hash len, then each element. Consistent with [`PartialEq`].
+impl<Traits: ?Sized + Hashable + Trait, M: MemBuilder> Hash for AnyVec<Traits, M>{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        let hash_fn = self.element_hash();
+        for i in 0..self.len(){
+            unsafe{ (hash_fn)(self.raw.get_unchecked(i), state) }
+        }
+    }
+}
+
 impl<Traits, M, A> Extend<A> for AnyVec<Traits, M>
 where
     Traits: ?Sized + Trait, 
@@ -850,12 +1807,33 @@ where
     }
 }
 
+/// Print elements, if [`Debuggable`]. Only metadata otherwise.
 impl<Traits: ?Sized + Trait, M: MemBuilder> Debug for AnyVec<Traits, M>{
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("AnyVec")
-         .field("typeid", &self.element_typeid())
-         .field("len", &self.len())
-         .finish()
+        if let Some(debug_fn) = self.debug_fn() {
+            f.debug_list()
+             .entries((0..self.len()).map(|i| DebugElement{
+                 debug_fn,
+                 element: unsafe{ self.raw.get_unchecked(i) }
+             }))
+             .finish()
+        } else {
+            f.debug_struct("AnyVec")
+             .field("typeid", &self.element_typeid())
+             .field("len", &self.len())
+             .finish()
+        }
+    }
+}
+
+struct DebugElement{
+    debug_fn: DebugFn,
+    element: *const u8
+}
+impl Debug for DebugElement{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        unsafe{ (self.debug_fn)(self.element, f) }
     }
 }
 
@@ -1,11 +1,11 @@
-use core::{cmp, mem, ptr};
+use core::{cmp, ptr};
 use core::alloc::Layout;
-use core::any::TypeId;
 use core::mem::size_of;
 use crate::any_value::{AnyValue, Unknown, AnyValueSizeless};
 use crate::assert_types_equal;
 use crate::clone_type::CloneFn;
-use crate::mem::{Mem, MemBuilder, MemResizable};
+use crate::element_vtable::ElementVTable;
+use crate::mem::{Mem, MemBuilder, MemResizable, TryReserveError};
 
 pub type DropFn = unsafe fn(ptr: *mut u8, len: usize);
 
@@ -13,31 +13,17 @@ pub struct AnyVecRaw<M: MemBuilder> {
     pub(crate) mem_builder: M,         // usually ZST
     pub(crate) mem: M::Mem,
     pub(crate) len: usize,  // in elements
-    pub(crate) type_id: TypeId,        // purely for safety checks
-    pub(crate) drop_fn: Option<DropFn>
+    pub(crate) vtable: &'static ElementVTable
 }
 
 impl<M: MemBuilder> AnyVecRaw<M> {
     #[inline]
-    pub fn new<T: 'static>(mem_builder: M, mem: M::Mem) -> Self {
+    pub fn new(mem_builder: M, mem: M::Mem, vtable: &'static ElementVTable) -> Self {
         Self{
             mem_builder,
             mem,
             len: 0,
-            type_id: TypeId::of::<T>(),
-            drop_fn:
-                if !mem::needs_drop::<T>(){
-                    None
-                } else{
-                    Some(|mut ptr: *mut u8, len: usize|{
-                        for _ in 0..len{
-                            unsafe{
-                                ptr::drop_in_place(ptr as *mut T);
-                                ptr = ptr.add(mem::size_of::<T>());
-                            }
-                        }
-                    })
-                }
+            vtable
         }
     }
 
@@ -53,8 +39,7 @@ impl<M: MemBuilder> AnyVecRaw<M> {
             mem_builder,
             mem,
             len: 0,
-            type_id: self.type_id,
-            drop_fn: self.drop_fn,
+            vtable: self.vtable,
         }
     }
 
@@ -85,7 +70,7 @@ impl<M: MemBuilder> AnyVecRaw<M> {
 
     #[inline]
     pub(crate) fn type_check<V: AnyValue>(&self, value: &V){
-        assert_types_equal(value.value_typeid(), self.type_id);
+        assert_types_equal(value.value_typeid(), self.vtable.type_id);
     }
 
     #[inline]
@@ -111,6 +96,15 @@ impl<M: MemBuilder> AnyVecRaw<M> {
         }
     }
 
+    #[inline]
+    pub(crate) fn try_reserve_one(&mut self) -> Result<(), TryReserveError>{
+        if self.len == self.capacity(){
+            self.mem.try_expand(1)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Leave this, for Mem, because implementation need it.
     /// If M::Mem does not implement MemResizable, then `expand`
     /// will panic, if out of capacity.
@@ -132,6 +126,30 @@ impl<M: MemBuilder> AnyVecRaw<M> {
         }
     }
 
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let new_len = self.len.checked_add(additional)
+            .ok_or(TryReserveError::CapacityExceeded)?;
+        if self.capacity() < new_len{
+            self.mem.try_expand(new_len - self.capacity())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError>
+        where M::Mem: MemResizable
+    {
+        let new_len = self.len.checked_add(additional)
+            .ok_or(TryReserveError::CapacityExceeded)?;
+        if self.capacity() < new_len{
+            self.mem.try_expand_exact(new_len - self.capacity())
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn shrink_to_fit(&mut self)
         where M::Mem: MemResizable
     {
@@ -242,6 +260,42 @@ impl<M: MemBuilder> AnyVecRaw<M> {
         
     }
 
+    pub(crate) fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "Index out of range!");
+        let other_len = self.len - at;
+
+        let mut other = self.clone_empty();
+        other.reserve(other_len);
+        unsafe{
+            ptr::copy_nonoverlapping(
+                self.get_unchecked(at),
+                other.get_unchecked_mut(0),
+                self.element_layout().size() * other_len
+            );
+        }
+
+        self.len = at;
+        other.len = other_len;
+        other
+    }
+
+    pub(crate) fn truncate(&mut self, len: usize){
+        if len >= self.len {
+            return;
+        }
+        let old_len = self.len;
+
+        // Prematurely set the length, so that even if dropping the values panics users
+        // won't be able to access the dropped values.
+        self.len = len;
+
+        if let Some(drop_fn) = self.vtable.drop{
+            unsafe{
+                (drop_fn)(self.get_unchecked_mut(len), old_len - len);
+            }
+        }
+    }
+
     #[inline]
     pub fn clear(&mut self){
         let len = self.len;
@@ -250,7 +304,7 @@ impl<M: MemBuilder> AnyVecRaw<M> {
         // won't be able to access the dropped values.
         self.len = 0;
 
-        if let Some(drop_fn) = self.drop_fn{
+        if let Some(drop_fn) = self.vtable.drop{
             unsafe{
                 (drop_fn)(self.mem.as_mut_ptr(), len);
             }
@@ -1,3 +1,8 @@
+#[cfg(feature="alloc")]
+extern crate alloc;
+
+#[cfg(feature="alloc")]
+use alloc::vec::Vec;
 use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
@@ -6,11 +11,11 @@ use core::ptr::NonNull;
 use core::{fmt, slice};
 use crate::any_value::{AnyValueSizeless, AnyValueWrapper};
 use crate::any_vec_raw::AnyVecRaw;
-use crate::ops::{Iter, pop, remove, swap_remove, TempValue};
+use crate::ops::{Iter, pop, remove, retain, swap_remove, TempValue};
 use crate::any_vec_ptr::AnyVecRawPtr;
 use crate::into_range;
 use crate::iter::ElementIterator;
-use crate::mem::{MemBuilder, Mem, MemResizable};
+use crate::mem::{MemBuilder, Mem, MemResizable, TryInsertError, TryReserveError};
 use crate::ops::drain::Drain;
 use crate::ops::splice::Splice;
 
@@ -78,6 +83,18 @@ impl<'a, T: 'static, M: MemBuilder + 'a> AnyVecTyped<'a, T, M>{
         self.this_mut().reserve_exact(additional)
     }
 
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.this_mut().try_reserve(additional)
+    }
+
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError>
+        where M::Mem: MemResizable
+    {
+        self.this_mut().try_reserve_exact(additional)
+    }
+
     #[inline]
     pub fn shrink_to_fit(&mut self)
         where M::Mem: MemResizable
@@ -104,6 +121,21 @@ impl<'a, T: 'static, M: MemBuilder + 'a> AnyVecTyped<'a, T, M>{
         }
     }
 
+    /// Return `value` back with [`TryInsertError`], if out of memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is out of bounds.
+    #[inline]
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), TryInsertError<T>>{
+        assert!(index <= self.len(), "Index out of range!");
+        if let Err(error) = self.this_mut().try_reserve_one(){
+            return Err(TryInsertError{error, value});
+        }
+        self.insert(index, value);
+        Ok(())
+    }
+
     #[inline]
     pub fn push(&mut self, value: T){
         unsafe{
@@ -111,6 +143,28 @@ impl<'a, T: 'static, M: MemBuilder + 'a> AnyVecTyped<'a, T, M>{
         }
     }
 
+    /// Return `value` back with [`TryInsertError`], if out of memory.
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), TryInsertError<T>>{
+        if let Err(error) = self.this_mut().try_reserve_one(){
+            return Err(TryInsertError{error, value});
+        }
+        self.push(value);
+        Ok(())
+    }
+
+    /// Fallible [`Extend::extend`]. See [`AnyVec::try_extend`].
+    ///
+    /// [`AnyVec::try_extend`]: crate::AnyVec::try_extend
+    pub fn try_extend<I: IntoIterator<Item=T>>(&mut self, iter: I) -> Result<(), TryInsertError<T>>{
+        let iter = iter.into_iter();
+        let _ = self.this_mut().try_reserve(iter.size_hint().0);
+        for v in iter {
+            self.try_push(v)?;
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn pop(&mut self) -> Option<T> {
         if self.is_empty(){
@@ -160,6 +214,39 @@ impl<'a, T: 'static, M: MemBuilder + 'a> AnyVecTyped<'a, T, M>{
         }
     }    
 
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// See [`AnyVec::retain`].
+    ///
+    /// [`AnyVec::retain`]: crate::AnyVec::retain
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        retain::retain(
+            AnyVecRawPtr::<T, M>::from(self.any_vec),
+            |element| f(unsafe{ &*(element as *const T) })
+        );
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable
+    /// reference to it.
+    ///
+    /// See [`AnyVec::retain_mut`].
+    ///
+    /// [`AnyVec::retain_mut`]: crate::AnyVec::retain_mut
+    #[inline]
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool
+    {
+        retain::retain(
+            AnyVecRawPtr::<T, M>::from(self.any_vec),
+            |element| f(unsafe{ &mut *(element as *mut T) })
+        );
+    }
+
     #[inline]
     pub fn drain(&mut self, range: impl RangeBounds<usize>)
         -> impl ElementIterator<Item = T> + 'a
@@ -195,6 +282,11 @@ impl<'a, T: 'static, M: MemBuilder + 'a> AnyVecTyped<'a, T, M>{
         })
     }
 
+    #[inline]
+    pub fn truncate(&mut self, len: usize){
+        self.this_mut().truncate(len);
+    }
+
     #[inline]
     pub fn clear(&mut self){
         self.this_mut().clear();
@@ -270,6 +362,16 @@ impl<'a, T: 'static, M: MemBuilder + 'a> AnyVecTyped<'a, T, M>{
         }
     }
 
+    /// Copy elements into new [`Vec`].
+    #[cfg(feature="alloc")]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone
+    {
+        self.as_slice().to_vec()
+    }
+
     #[inline]
     pub fn spare_capacity_mut(&mut self) -> &'a mut[MaybeUninit<T>] {
         unsafe {
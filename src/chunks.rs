@@ -0,0 +1,245 @@
+use core::cmp::min;
+use core::iter::FusedIterator;
+use crate::{AnySlice, AnySliceMut};
+
+/// [`AnySlice`] iterator over `size` elements [`AnySlice`]s. Last chunk may be shorter.
+///
+/// This is created by [`AnyVec::chunks`].
+///
+/// [`AnyVec::chunks`]: crate::AnyVec::chunks
+#[derive(Clone)]
+pub struct Chunks<'a>{
+    slice: AnySlice<'a>,
+    size: usize,
+}
+
+impl<'a> Chunks<'a>{
+    #[inline]
+    pub(crate) fn new(slice: AnySlice<'a>, size: usize) -> Self {
+        assert!(size != 0, "Chunk size must be non-zero!");
+        Self{slice, size}
+    }
+}
+
+impl<'a> Iterator for Chunks<'a>{
+    type Item = AnySlice<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (chunk, rest) = self.slice.split_at(min(self.size, self.slice.len()));
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Chunks<'a>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let len = self.slice.len();
+            let remainder = len % self.size;
+            let chunk_size = if remainder == 0 { self.size } else { remainder };
+            let (rest, chunk) = self.slice.split_at(len - chunk_size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Chunks<'a>{}
+impl<'a> FusedIterator for Chunks<'a>{}
+
+/// [`AnySliceMut`] iterator over `size` elements [`AnySliceMut`]s. Last chunk may be shorter.
+///
+/// This is created by [`AnyVec::chunks_mut`].
+///
+/// [`AnyVec::chunks_mut`]: crate::AnyVec::chunks_mut
+pub struct ChunksMut<'a>{
+    slice: AnySliceMut<'a>,
+    size: usize,
+}
+
+impl<'a> ChunksMut<'a>{
+    #[inline]
+    pub(crate) fn new(slice: AnySliceMut<'a>, size: usize) -> Self {
+        assert!(size != 0, "Chunk size must be non-zero!");
+        Self{slice, size}
+    }
+}
+
+impl<'a> Iterator for ChunksMut<'a>{
+    type Item = AnySliceMut<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let mid = min(self.size, self.slice.len());
+            let (chunk, rest) = self.slice.take().into_split_at(mid);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for ChunksMut<'a>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let len = self.slice.len();
+            let remainder = len % self.size;
+            let chunk_size = if remainder == 0 { self.size } else { remainder };
+            let (rest, chunk) = self.slice.take().into_split_at(len - chunk_size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ChunksMut<'a>{}
+impl<'a> FusedIterator for ChunksMut<'a>{}
+
+/// [`AnySlice`] iterator over exactly `size` elements [`AnySlice`]s.
+/// Elements, that do not fit, are accessible with [`remainder`].
+///
+/// This is created by [`AnyVec::chunks_exact`].
+///
+/// [`AnyVec::chunks_exact`]: crate::AnyVec::chunks_exact
+/// [`remainder`]: Self::remainder
+#[derive(Clone)]
+pub struct ChunksExact<'a>{
+    slice: AnySlice<'a>,
+    remainder: AnySlice<'a>,
+    size: usize,
+}
+
+impl<'a> ChunksExact<'a>{
+    #[inline]
+    pub(crate) fn new(slice: AnySlice<'a>, size: usize) -> Self {
+        assert!(size != 0, "Chunk size must be non-zero!");
+        let len = slice.len();
+        let (slice, remainder) = slice.split_at(len - len % size);
+        Self{slice, remainder, size}
+    }
+
+    /// Last up to `size - 1` elements, that will not be returned by iterator.
+    #[inline]
+    pub fn remainder(&self) -> AnySlice<'a> {
+        self.remainder
+    }
+}
+
+impl<'a> Iterator for ChunksExact<'a>{
+    type Item = AnySlice<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (chunk, rest) = self.slice.split_at(self.size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len() / self.size;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for ChunksExact<'a>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            None
+        } else {
+            let (rest, chunk) = self.slice.split_at(self.slice.len() - self.size);
+            self.slice = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for ChunksExact<'a>{}
+impl<'a> FusedIterator for ChunksExact<'a>{}
+
+/// [`AnySlice`] iterator over overlapping `size` elements [`AnySlice`]s.
+///
+/// This is created by [`AnyVec::windows`].
+///
+/// [`AnyVec::windows`]: crate::AnyVec::windows
+#[derive(Clone)]
+pub struct Windows<'a>{
+    slice: AnySlice<'a>,
+    size: usize,
+}
+
+impl<'a> Windows<'a>{
+    #[inline]
+    pub(crate) fn new(slice: AnySlice<'a>, size: usize) -> Self {
+        assert!(size != 0, "Window size must be non-zero!");
+        Self{slice, size}
+    }
+}
+
+impl<'a> Iterator for Windows<'a>{
+    type Item = AnySlice<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.len() < self.size {
+            None
+        } else {
+            let window = self.slice.split_at(self.size).0;
+            self.slice = self.slice.split_at(1).1;
+            Some(window)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len().saturating_sub(self.size - 1);
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Windows<'a>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.slice.len();
+        if len < self.size {
+            None
+        } else {
+            let window = self.slice.split_at(len - self.size).1;
+            self.slice = self.slice.split_at(len - 1).0;
+            Some(window)
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Windows<'a>{}
+impl<'a> FusedIterator for Windows<'a>{}
@@ -2,75 +2,155 @@
 //! Trait object based compile-time dispatch.
 //!
 
+use core::cmp::Ordering;
+use core::{fmt, mem, ptr};
+use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
 use crate::traits::*;
 
+/// Function table of markers without functions.
 #[derive(Copy, Clone, Default)]
 pub struct Empty;
 
+/// Clone `len` elements from `src` to uninitialized `dst`.
+///
+/// If clone panics, already cloned elements should be dropped. Otherwise they leak.
 pub type CloneFn = unsafe fn(src: *const u8, dst: *mut u8, len: usize);
 unsafe fn clone_fn<T: Clone>(src: *const u8, dst: *mut u8, len: usize){
+    /// Drop cloned elements on panic.
+    struct Guard<T>{
+        dst: *mut T,
+        cloned: usize,
+    }
+    impl<T> Drop for Guard<T>{
+        #[inline]
+        fn drop(&mut self) {
+            unsafe{ ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.dst, self.cloned)); }
+        }
+    }
+
     let src = src as *const T;
-    let dst = dst as *mut T;
+    let mut guard = Guard{ dst: dst as *mut T, cloned: 0 };
     for i in 0..len {
-        let dst = dst.add(i);
+        let dst = guard.dst.add(i);
         let src = src.add(i);
         dst.write((*src).clone());
+        guard.cloned += 1;
     }
+    mem::forget(guard);
 }
-fn nop_fn(_: *const u8, _: *mut u8, _: usize){}
 
-
-pub trait CloneFnTrait<Traits: ?Sized>{
-    const CLONE_FN: CloneFn = nop_fn;
-}
-impl<T: Clone> CloneFnTrait<dyn Cloneable> for T{
-    const CLONE_FN: CloneFn = clone_fn::<T>;
-}
-impl<T: Clone> CloneFnTrait<dyn Cloneable+Send> for T{
-    const CLONE_FN: CloneFn = clone_fn::<T>;
+pub type DebugFn = unsafe fn(ptr: *const u8, f: &mut Formatter<'_>) -> fmt::Result;
+unsafe fn debug_fn<T: Debug>(ptr: *const u8, f: &mut Formatter<'_>) -> fmt::Result{
+    (*(ptr as *const T)).fmt(f)
 }
-impl<T: Clone> CloneFnTrait<dyn Cloneable+Sync> for T{
-    const CLONE_FN: CloneFn = clone_fn::<T>;
+
+pub type EqFn = unsafe fn(lhs: *const u8, rhs: *const u8) -> bool;
+unsafe fn eq_fn<T: PartialEq>(lhs: *const u8, rhs: *const u8) -> bool{
+    *(lhs as *const T) == *(rhs as *const T)
 }
-impl<T: Clone> CloneFnTrait<dyn Cloneable+Send+Sync> for T{
-    const CLONE_FN: CloneFn = clone_fn::<T>;
+
+pub type HashFn = unsafe fn(ptr: *const u8, state: &mut dyn Hasher);
+unsafe fn hash_fn<T: Hash>(ptr: *const u8, mut state: &mut dyn Hasher){
+    (*(ptr as *const T)).hash(&mut state)
 }
-impl<T> CloneFnTrait<dyn None> for T{}
-impl<T> CloneFnTrait<dyn Send> for T{}
-impl<T> CloneFnTrait<dyn Sync> for T{}
-impl<T> CloneFnTrait<dyn Send+Sync> for T{}
-
-
-/// This all just to replace AnyVec's clone function pointer with ZST,
-/// when non-Cloneable.
-pub trait CloneType{
-    type Type: Copy;
-    fn new(f: CloneFn) -> Self::Type;
-    fn get(f: Self::Type) -> CloneFn;
+
+pub type CmpFn = unsafe fn(lhs: *const u8, rhs: *const u8) -> Ordering;
+unsafe fn cmp_fn<T: Ord>(lhs: *const u8, rhs: *const u8) -> Ordering{
+    (*(lhs as *const T)).cmp(&*(rhs as *const T))
 }
-macro_rules! impl_clone_type_empty {
-    ($t:ty) => {
-        impl CloneType for $t {
-            type Type = Empty;
-            fn new(_: CloneFn) -> Self::Type{ Empty }
-            fn get(_: Self::Type) -> CloneFn{ nop_fn }
+
+/// Implement [`Trait`] and [`FnTableFor`] for built-in markers.
+///
+/// `$body` - [`FnTableFor`] implementation,
+/// `$t: $bound` - marker, and requirements for `T`.
+macro_rules! impl_trait {
+    (
+        $body:tt
+        $($t:ty: $($bound:path),*;)*
+    ) => {$(
+        impl Trait for $t{
+            type FnTable = Empty;
         }
-    }
+        unsafe impl<T: $($bound +)*> FnTableFor<T> for $t $body
+    )*}
 }
-macro_rules! impl_clone_type_fn {
-    ($t:ty) => {
-        impl CloneType for $t {
-            type Type = CloneFn;
-            fn new(f: CloneFn) -> Self::Type{ f }
-            fn get(f: Self::Type) -> CloneFn{ f as CloneFn }
-        }
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
     }
-}
-impl_clone_type_empty!(dyn None);
-impl_clone_type_empty!(dyn Sync);
-impl_clone_type_empty!(dyn Send);
-impl_clone_type_empty!(dyn Send + Sync);
-impl_clone_type_fn!(dyn Cloneable);
-impl_clone_type_fn!(dyn Cloneable + Send);
-impl_clone_type_fn!(dyn Cloneable + Sync);
-impl_clone_type_fn!(dyn Cloneable + Send + Sync);
+    dyn None: ;
+    dyn Send: Send;
+    dyn Sync: Sync;
+    dyn Send + Sync: Send, Sync;
+);
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
+        const CLONE_FN: Option<CloneFn> = Some(clone_fn::<T>);
+    }
+    dyn Cloneable: Clone;
+    dyn Cloneable + Send: Clone, Send;
+    dyn Cloneable + Sync: Clone, Sync;
+    dyn Cloneable + Send + Sync: Clone, Send, Sync;
+);
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
+        const DEBUG_FN: Option<DebugFn> = Some(debug_fn::<T>);
+    }
+    dyn Debuggable: Debug;
+    dyn Debuggable + Send: Debug, Send;
+    dyn Debuggable + Sync: Debug, Sync;
+    dyn Debuggable + Send + Sync: Debug, Send, Sync;
+);
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
+        const CLONE_FN: Option<CloneFn> = Some(clone_fn::<T>);
+        const DEBUG_FN: Option<DebugFn> = Some(debug_fn::<T>);
+    }
+    dyn CloneableDebuggable: Clone, Debug;
+    dyn CloneableDebuggable + Send: Clone, Debug, Send;
+    dyn CloneableDebuggable + Sync: Clone, Debug, Sync;
+    dyn CloneableDebuggable + Send + Sync: Clone, Debug, Send, Sync;
+);
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
+        const EQ_FN: Option<EqFn> = Some(eq_fn::<T>);
+    }
+    dyn Comparable: PartialEq;
+    dyn Comparable + Send: PartialEq, Send;
+    dyn Comparable + Sync: PartialEq, Sync;
+    dyn Comparable + Send + Sync: PartialEq, Send, Sync;
+);
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
+        const EQ_FN: Option<EqFn> = Some(eq_fn::<T>);
+        const HASH_FN: Option<HashFn> = Some(hash_fn::<T>);
+    }
+    dyn Hashable: Hash, Eq;
+    dyn Hashable + Send: Hash, Eq, Send;
+    dyn Hashable + Sync: Hash, Eq, Sync;
+    dyn Hashable + Send + Sync: Hash, Eq, Send, Sync;
+);
+
+impl_trait!(
+    {
+        const FN_TABLE: Empty = Empty;
+        const EQ_FN: Option<EqFn> = Some(eq_fn::<T>);
+        const CMP_FN: Option<CmpFn> = Some(cmp_fn::<T>);
+    }
+    dyn Ordered: Ord;
+    dyn Ordered + Send: Ord, Send;
+    dyn Ordered + Sync: Ord, Sync;
+    dyn Ordered + Send + Sync: Ord, Send, Sync;
+);
@@ -1,14 +1,18 @@
 use core::any::TypeId;
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::{Debug, Formatter};
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
 use core::ops::{Deref, DerefMut};
 use core::ptr::NonNull;
-use crate::any_value::{AnyValue, AnyValueCloneable, AnyValueMut, AnyValueTypelessMut, AnyValueTypeless, AnyValueSizeless, AnyValueSizelessMut};
+use crate::any_value::{AnyValue, DowncastError, AnyValueCloneable, AnyValueMut, AnyValueTypelessMut, AnyValueTypeless, AnyValueSizeless, AnyValueSizelessMut};
 use crate::any_vec_raw::AnyVecRaw;
 use crate::any_vec_ptr::{AnyVecPtr, IAnyVecPtr, IAnyVecRawPtr};
 use crate::{AnyVec, mem};
 use crate::mem::MemBuilder;
-use crate::traits::{Cloneable, None, Trait};
+use crate::traits::{Cloneable, Comparable, Debuggable, Hashable, None, Ordered, Trait};
 
 // Typed operations will never use type-erased ElementPointer, so there is no
 // need in type-known-based optimizations.
@@ -68,6 +72,13 @@ impl<'a, AnyVecPtr: IAnyVecRawPtr> ElementPointer<'a, AnyVecPtr>{
         }
     }
 
+    /// Same as [`AnyValue::try_downcast_ref`], but return `&'a T`, instead of `&T`.
+    #[inline]
+    pub fn try_downcast_ref<T: 'static>(&self) -> Result<&'a T, DowncastError>{
+        self.downcast_ref::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(self.value_type_name()))
+    }
+
     /// Same as [`AnyValueSizeless::downcast_ref_unchecked`], but return `&'a T`, instead of `&T`.
     #[inline]
     pub unsafe fn downcast_ref_unchecked<T: 'static>(&self) -> &'a T{
@@ -84,6 +95,14 @@ impl<'a, AnyVecPtr: IAnyVecRawPtr> ElementPointer<'a, AnyVecPtr>{
         }
     }
 
+    /// Same as [`AnyValueMut::try_downcast_mut`], but return `&'a mut T`, instead of `&mut T`.
+    #[inline]
+    pub fn try_downcast_mut<T: 'static>(&mut self) -> Result<&'a mut T, DowncastError>{
+        let type_name = self.value_type_name();
+        self.downcast_mut::<T>()
+            .ok_or_else(|| DowncastError::new::<T>(type_name))
+    }
+
     /// Same as [`AnyValueSizelessMut::downcast_mut_unchecked`], but return `&'a mut T`, instead of `&mut T`.
     #[inline]
     pub unsafe fn downcast_mut_unchecked<T: 'static>(&mut self) -> &'a mut T{
@@ -94,7 +113,7 @@ impl<'a, AnyVecPtr: IAnyVecRawPtr> ElementPointer<'a, AnyVecPtr>{
 impl<'a, AnyVecPtr: IAnyVecRawPtr> Drop for ElementPointer<'a, AnyVecPtr>{
     #[inline]
     fn drop(&mut self) {
-        if let Some(drop_fn) = self.any_vec_raw().drop_fn{
+        if let Some(drop_fn) = self.any_vec_raw().vtable.drop{
             unsafe{
                 (drop_fn)(self.element.as_ptr(), 1);
             }
@@ -119,7 +138,12 @@ impl<'a, AnyVecPtr: IAnyVecRawPtr> AnyValueTypeless for ElementPointer<'a, AnyVe
 impl<'a, AnyVecPtr: IAnyVecRawPtr> AnyValue for ElementPointer<'a, AnyVecPtr>{
     #[inline]
     fn value_typeid(&self) -> TypeId {
-        self.any_vec_raw().type_id
+        self.any_vec_raw().vtable.type_id
+    }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        (self.any_vec_raw().vtable.type_name)()
     }
 }
 
@@ -137,11 +161,63 @@ impl<'a, Traits: ?Sized + Cloneable + Trait, M: MemBuilder>
 {
     #[inline]
     unsafe fn clone_into(&self, out: *mut u8) {
-        let clone_fn = self.any_vec_ptr.any_vec().clone_fn();
+        let clone_fn = self.any_vec_ptr.any_vec().element_clone();
         (clone_fn)(self.as_bytes().as_ptr(), out, 1);
     }
 }
 
+impl<'a, Traits: ?Sized + Debuggable + Trait, M: MemBuilder>
+    Debug for ElementPointer<'a, AnyVecPtr<Traits, M>>
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let debug_fn = unsafe{ self.any_vec_ptr.any_vec() }.element_debug();
+        unsafe{ (debug_fn)(self.as_bytes_ptr(), f) }
+    }
+}
+
+/// Elements with different types are never equal.
+impl<'a, 'b, Traits: ?Sized + Comparable + Trait, M: MemBuilder, OtherM: MemBuilder>
+    PartialEq<ElementPointer<'b, AnyVecPtr<Traits, OtherM>>> for ElementPointer<'a, AnyVecPtr<Traits, M>>
+{
+    #[inline]
+    fn eq(&self, other: &ElementPointer<'b, AnyVecPtr<Traits, OtherM>>) -> bool {
+        if self.value_typeid() != other.value_typeid(){
+            return false;
+        }
+        let eq_fn = unsafe{ self.any_vec_ptr.any_vec() }.element_eq();
+        unsafe{ (eq_fn)(self.as_bytes_ptr(), other.as_bytes_ptr()) }
+    }
+}
+
+/// Elements with different types are not comparable.
+impl<'a, 'b, Traits: ?Sized + Ordered + Trait, M: MemBuilder, OtherM: MemBuilder>
+    PartialOrd<ElementPointer<'b, AnyVecPtr<Traits, OtherM>>> for ElementPointer<'a, AnyVecPtr<Traits, M>>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ElementPointer<'b, AnyVecPtr<Traits, OtherM>>) -> Option<Ordering> {
+        if self.value_typeid() != other.value_typeid(){
+            return None;
+        }
+        let cmp_fn = unsafe{ self.any_vec_ptr.any_vec() }.element_cmp();
+        Some(unsafe{ (cmp_fn)(self.as_bytes_ptr(), other.as_bytes_ptr()) })
+    }
+}
+
+impl<'a, Traits: ?Sized + Hashable + Trait, M: MemBuilder>
+    Eq for ElementPointer<'a, AnyVecPtr<Traits, M>>
+{}
+
+impl<'a, Traits: ?Sized + Hashable + Trait, M: MemBuilder>
+    Hash for ElementPointer<'a, AnyVecPtr<Traits, M>>
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hash_fn = unsafe{ self.any_vec_ptr.any_vec() }.element_hash();
+        unsafe{ (hash_fn)(self.as_bytes_ptr(), state) }
+    }
+}
+
 unsafe impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Send
 for
     ElementPointer<'a, AnyVecPtr<Traits, M>>
@@ -187,6 +263,11 @@ pub struct ElementRef<'a, Traits: ?Sized + Trait = dyn None, M: MemBuilder = mem
     pub(crate) ManuallyDrop<Element<'a, Traits, M>>
 );
 impl<'a, Traits: ?Sized + Trait, M: MemBuilder> ElementReference<'a, Traits, M> for ElementRef<'a, Traits, M>{}
+// Like &T, can be sent if T: Sync.
+unsafe impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Send for ElementRef<'a, Traits, M>
+where
+    AnyVec<Traits, M>: Sync
+{}
 impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Deref for ElementRef<'a, Traits, M>{
     type Target = Element<'a, Traits, M>;
 
@@ -201,6 +282,35 @@ impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Clone for ElementRef<'a, Traits,
         Self(ManuallyDrop::new(self.0.clone()))
     }
 }
+impl<'a, Traits: ?Sized + Debuggable + Trait, M: MemBuilder> Debug for ElementRef<'a, Traits, M>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+impl<'a, 'b, Traits: ?Sized + Comparable + Trait, M: MemBuilder, OtherM: MemBuilder>
+    PartialEq<ElementRef<'b, Traits, OtherM>> for ElementRef<'a, Traits, M>
+{
+    #[inline]
+    fn eq(&self, other: &ElementRef<'b, Traits, OtherM>) -> bool {
+        **self == **other
+    }
+}
+impl<'a, 'b, Traits: ?Sized + Ordered + Trait, M: MemBuilder, OtherM: MemBuilder>
+    PartialOrd<ElementRef<'b, Traits, OtherM>> for ElementRef<'a, Traits, M>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ElementRef<'b, Traits, OtherM>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+impl<'a, Traits: ?Sized + Hashable + Trait, M: MemBuilder> Eq for ElementRef<'a, Traits, M>{}
+impl<'a, Traits: ?Sized + Hashable + Trait, M: MemBuilder> Hash for ElementRef<'a, Traits, M>{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
 
 /// Mutable reference to [`AnyVec`] element.
 ///
@@ -224,4 +334,33 @@ impl<'a, Traits: ?Sized + Trait, M: MemBuilder> DerefMut for ElementMut<'a, Trai
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
-}
\ No newline at end of file
+}
+impl<'a, Traits: ?Sized + Debuggable + Trait, M: MemBuilder> Debug for ElementMut<'a, Traits, M>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+impl<'a, 'b, Traits: ?Sized + Comparable + Trait, M: MemBuilder, OtherM: MemBuilder>
+    PartialEq<ElementMut<'b, Traits, OtherM>> for ElementMut<'a, Traits, M>
+{
+    #[inline]
+    fn eq(&self, other: &ElementMut<'b, Traits, OtherM>) -> bool {
+        **self == **other
+    }
+}
+impl<'a, 'b, Traits: ?Sized + Ordered + Trait, M: MemBuilder, OtherM: MemBuilder>
+    PartialOrd<ElementMut<'b, Traits, OtherM>> for ElementMut<'a, Traits, M>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &ElementMut<'b, Traits, OtherM>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+impl<'a, Traits: ?Sized + Hashable + Trait, M: MemBuilder> Eq for ElementMut<'a, Traits, M>{}
+impl<'a, Traits: ?Sized + Hashable + Trait, M: MemBuilder> Hash for ElementMut<'a, Traits, M>{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
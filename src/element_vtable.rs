@@ -0,0 +1,76 @@
+use core::alloc::Layout;
+use core::any::{type_name, Any, TypeId};
+use core::{mem, ptr};
+use crate::any_vec_raw::DropFn;
+use crate::clone_type::{CloneFn, CmpFn, DebugFn, EqFn, HashFn};
+use crate::SatisfyTraits;
+use crate::traits::{FnTableFor, Trait};
+
+pub(crate) unsafe fn drop_fn<T>(mut ptr: *mut u8, len: usize){
+    for _ in 0..len{
+        ptr::drop_in_place(ptr as *mut T);
+        ptr = ptr.add(mem::size_of::<T>());
+    }
+}
+
+fn as_any_fn<T: 'static>(ptr: *mut u8) -> *mut dyn Any{
+    ptr as *mut T
+}
+
+/// [`AnyVec`] element type description.
+///
+/// One `'static` table per element type and [`Trait`] constraints, referenced
+/// by [`AnyVec`]. So [`AnyVec`] size does not depend on number of functions.
+///
+/// Normally obtained with [`ElementVTable::of`]. Can be filled manually for
+/// types, known only at runtime (e.g. provided by plugin), and used with
+/// [`AnyVec::from_vtable`].
+///
+/// Optional functions are used only if [`AnyVec`] have corresponding trait constraint.
+/// Operation with missing function will panic.
+///
+/// [`AnyVec`]: crate::AnyVec
+/// [`AnyVec::from_vtable`]: crate::AnyVec::from_vtable
+#[derive(Copy, Clone)]
+pub struct ElementVTable{
+    pub layout: Layout,
+    pub type_id: TypeId,
+    /// Diagnostic only.
+    pub type_name: fn() -> &'static str,
+    /// `None` if element does not need drop.
+    pub drop: Option<DropFn>,
+    /// Element pointer to `dyn Any` pointer cast. Used by [`AnyValueBox::into_any`].
+    ///
+    /// [`AnyValueBox::into_any`]: crate::any_value::AnyValueBox::into_any
+    pub as_any: Option<fn(*mut u8) -> *mut dyn Any>,
+    pub clone: Option<CloneFn>,
+    pub debug: Option<DebugFn>,
+    pub eq: Option<EqFn>,
+    pub hash: Option<HashFn>,
+    pub cmp: Option<CmpFn>,
+}
+
+impl ElementVTable{
+    /// Table for `T` with `Traits` functions.
+    #[inline]
+    pub const fn new<T: 'static, Traits: ?Sized + FnTableFor<T>>() -> Self {
+        Self{
+            layout: Layout::new::<T>(),
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>,
+            drop: if mem::needs_drop::<T>() { Some(drop_fn::<T>) } else { None },
+            as_any: Some(as_any_fn::<T>),
+            clone: Traits::CLONE_FN,
+            debug: Traits::DEBUG_FN,
+            eq: Traits::EQ_FN,
+            hash: Traits::HASH_FN,
+            cmp: Traits::CMP_FN,
+        }
+    }
+
+    /// `'static` table for `T` with `Traits` functions.
+    #[inline]
+    pub fn of<T: SatisfyTraits<Traits>, Traits: ?Sized + Trait>() -> &'static Self {
+        T::VTABLE
+    }
+}
@@ -54,6 +54,24 @@
 //! let v1: AnyVec<dyn Sync + Send> = AnyVec::new::<Rc<usize>>();
 //!```
 //!
+//! [`Debuggable`] makes [`AnyVec`] and its elements print actual values:
+//!
+//! [`Debuggable`]: traits::Debuggable
+//!
+//!```rust
+//! # use any_vec::AnyVec;
+//! # use any_vec::traits::*;
+//! let mut v: AnyVec<dyn Debuggable> = AnyVec::new::<usize>();
+//! v.downcast_mut::<usize>().unwrap().push(1);
+//! assert_eq!(format!("{v:?}"), "[1]");
+//!```
+//!
+//! With `serde` feature, `traits::Serializable` makes [`AnyVec`] `Serialize`.
+//! `TypeRegistry` deserialize it back by stable type names.
+//!
+//! With `rayon` feature, `&AnyVec<dyn Sync>` and `&mut AnyVec<dyn Send>` are
+//! `IntoParallelIterator`, and `&mut AnyVec<dyn Send>` is `ParallelDrainRange`.
+//!
 //! # LazyClone
 //!
 //! Whenever possible, [`any_vec`] types implement [`AnyValueCloneable`], which
@@ -96,6 +114,8 @@
 //! any_vec.push(AnyValueWrapper::new(String::from("0")))
 //!```
 //!
+//! [`mem::SmallHeap`] makes SBO `SmallAnyVec`.
+//!
 //! With help of [`clone_empty_in`] you can use stack allocated, or SBO [`AnyVec`]
 //! as fast intermediate storage for values of unknown type:
 //!
@@ -113,7 +133,8 @@
 //!```
 //!
 //! [`MemBuilder`] interface, being stateful, allow to make [`Mem`],
-//! which can work with complex custom allocators.
+//! which can work with complex custom allocators. With `allocator_api2` feature,
+//! [`mem::Allocator`] works with any `allocator_api2` allocator.
 //!
 //! [`MemBuilder`]: mem::MemBuilder
 //! [`Mem`]: mem::Mem
@@ -150,20 +171,39 @@
 
 mod any_vec;
 mod clone_type;
+mod element_vtable;
 mod any_vec_ptr;
 mod any_vec_raw;
 mod any_vec_typed;
 mod iter;
+mod chunks;
+#[cfg(feature="serde")]
+mod serialize;
+#[cfg(feature="rayon")]
+mod par_iter;
 
 use core::any::TypeId;
 pub use crate::any_vec::{AnyVec, AnyVecMut, AnyVecRef, RawParts, SatisfyTraits, traits};
 pub use any_vec_typed::AnyVecTyped;
+pub use element_vtable::ElementVTable;
 pub use iter::{ElementIterator, Iter, IterMut, IterRef};
+pub use chunks::{Chunks, ChunksExact, ChunksMut, Windows};
+pub use any_slice::{AnySlice, AnySliceMut};
+#[cfg(feature="serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use type_registry::TypeRegistry;
+#[cfg(feature="rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use par_iter::{ParDrain, ParIter, ParIterMut, ParIterRef};
 
 pub mod mem;
 pub mod any_value;
 pub mod ops;
 pub mod element;
+pub mod any_slice;
+#[cfg(feature="serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod type_registry;
 
 use core::ptr;
 use core::ops::{Bound, Range, RangeBounds};
@@ -0,0 +1,115 @@
+use core::alloc::Layout;
+use core::cmp;
+use core::ptr::NonNull;
+use crate::mem::{Doubling, GrowthPolicy, Mem, MemBuilder, MemBuilderSizeable, MemRawParts, MemResizable, TryReserveError};
+use crate::mem::heap::HeapMem;
+
+/// Heap allocated memory, aligned to at least `ALIGN` bytes.
+///
+/// Like [`Heap`], but memory chunk alignment is `max(ALIGN, element align)`.
+/// Alignment kept across resizes and raw parts. Useful for SIMD kernels
+/// (`AlignedHeap<32>` for AVX), or cache line alignment (`AlignedHeap<64>`).
+///
+/// `ALIGN` must be power of two.
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::mem::AlignedHeap;
+/// # use any_vec::traits::None;
+/// let mut any_vec: AnyVec<dyn None, AlignedHeap<64>> = AnyVec::new::<f32>();
+/// any_vec.downcast_mut::<f32>().unwrap().extend([1.0, 2.0, 3.0]);
+/// assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+/// ```
+///
+/// [`Heap`]: super::Heap
+#[derive(Default, Clone, Copy)]
+pub struct AlignedHeap<const ALIGN: usize, P: GrowthPolicy = Doubling>(pub P);
+impl<const ALIGN: usize, P: GrowthPolicy> MemBuilder for AlignedHeap<ALIGN, P> {
+    /// Implements [`MemResizable`], [`MemRawParts`].
+    type Mem = AlignedHeapMem<ALIGN, P>;
+
+    #[inline]
+    fn build(&mut self, element_layout: Layout) -> Self::Mem {
+        const { assert!(ALIGN.is_power_of_two(), "ALIGN must be power of two!") };
+        AlignedHeapMem(HeapMem::new(
+            element_layout,
+            cmp::max(ALIGN, element_layout.align()),
+            self.0.clone()
+        ))
+    }
+}
+impl<const ALIGN: usize, P: GrowthPolicy> MemBuilderSizeable for AlignedHeap<ALIGN, P>{
+    #[inline]
+    fn build_with_size(&mut self, element_layout: Layout, capacity: usize) -> Self::Mem {
+        let mut mem = self.build(element_layout);
+        mem.resize(capacity);
+        mem
+    }
+}
+
+pub struct AlignedHeapMem<const ALIGN: usize, P: GrowthPolicy = Doubling>(HeapMem<P>);
+
+impl<const ALIGN: usize, P: GrowthPolicy> Mem for AlignedHeapMem<ALIGN, P> {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_mut_ptr()
+    }
+
+    #[inline]
+    fn element_layout(&self) -> Layout {
+        self.0.element_layout()
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+
+    #[inline]
+    fn expand(&mut self, additional: usize) {
+        self.0.expand(additional)
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.0.try_expand(additional)
+    }
+}
+
+impl<const ALIGN: usize, P: GrowthPolicy> MemResizable for AlignedHeapMem<ALIGN, P> {
+    #[inline]
+    fn resize(&mut self, new_size: usize) {
+        self.0.resize(new_size)
+    }
+
+    #[inline]
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
+        self.0.try_resize(new_size)
+    }
+}
+
+/// Handle carries [`GrowthPolicy`].
+impl<const ALIGN: usize, P: GrowthPolicy> MemRawParts for AlignedHeapMem<ALIGN, P>{
+    type Handle = (NonNull<u8>, P);
+
+    #[inline]
+    fn into_raw_parts(self) -> (Self::Handle, Layout, usize) {
+        let (mem, element_layout, size, policy) = self.0.into_raw_parts_with_policy();
+        ((mem, policy), element_layout, size)
+    }
+
+    /// `handle` must be allocated with `max(ALIGN, element_layout.align())` alignment.
+    #[inline]
+    unsafe fn from_raw_parts((mem, policy): Self::Handle, element_layout: Layout, size: usize) -> Self {
+        Self(HeapMem::from_raw_parts_aligned(
+            mem, element_layout, size,
+            cmp::max(ALIGN, element_layout.align()),
+            policy
+        ))
+    }
+}
@@ -0,0 +1,165 @@
+use core::alloc::Layout;
+use core::mem::ManuallyDrop;
+use core::ptr::NonNull;
+use allocator_api2::alloc::Allocator as AllocatorApi;
+use crate::mem::{dangling, handle_reserve, try_expand_with, Doubling, Mem, MemBuilder, MemBuilderSizeable, MemRawParts, MemResizable, TryReserveError};
+
+/// [`Allocator`] backed memory.
+///
+/// Works with any [`allocator_api2`] allocator. Like `bumpalo` arenas
+/// or custom pools.
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::mem::Allocator;
+/// # use any_vec::traits::None;
+/// let bump = bumpalo::Bump::new();
+/// let mut any_vec: AnyVec<dyn None, Allocator<&bumpalo::Bump>> = AnyVec::new_in::<usize>(Allocator(&bump));
+/// any_vec.downcast_mut::<usize>().unwrap().push(1);
+/// ```
+///
+/// [`Allocator`]: allocator_api2::alloc::Allocator
+/// [`allocator_api2`]: allocator_api2
+#[derive(Default, Clone, Copy)]
+pub struct Allocator<A: AllocatorApi + Clone>(pub A);
+
+impl<A: AllocatorApi + Clone> MemBuilder for Allocator<A> {
+    /// Implements [`MemResizable`], [`MemRawParts`].
+    type Mem = AllocatorMem<A>;
+
+    #[inline]
+    fn build(&mut self, element_layout: Layout) -> Self::Mem {
+        AllocatorMem {
+            mem: dangling(&element_layout),
+            size: 0,
+            element_layout,
+            allocator: self.0.clone()
+        }
+    }
+}
+
+impl<A: AllocatorApi + Clone> MemBuilderSizeable for Allocator<A> {
+    #[inline]
+    fn build_with_size(&mut self, element_layout: Layout, capacity: usize) -> Self::Mem {
+        let mut mem = self.build(element_layout);
+        mem.resize(capacity);
+        mem
+    }
+}
+
+pub struct AllocatorMem<A: AllocatorApi> {
+    mem: NonNull<u8>,
+    size: usize,        // in elements
+    element_layout: Layout, // size is aligned
+    allocator: A,
+}
+
+impl<A: AllocatorApi> AllocatorMem<A> {
+    /// Memory layout for `size` elements.
+    #[inline]
+    fn mem_layout(&self, size: usize) -> Result<Layout, TryReserveError> {
+        self.element_layout.size()
+            .checked_mul(size)
+            .and_then(|mem_size| Layout::from_size_align(mem_size, self.element_layout.align()).ok())
+            .ok_or(TryReserveError::CapacityExceeded)
+    }
+}
+
+impl<A: AllocatorApi> Mem for AllocatorMem<A> {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.mem.as_ptr()
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.mem.as_ptr()
+    }
+
+    #[inline]
+    fn element_layout(&self) -> Layout {
+        self.element_layout
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    fn expand(&mut self, additional: usize){
+        handle_reserve(self.try_expand(additional));
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError>{
+        try_expand_with(self, additional, &Doubling)
+    }
+}
+
+impl<A: AllocatorApi> MemResizable for AllocatorMem<A> {
+    #[inline]
+    fn resize(&mut self, new_size: usize) {
+        handle_reserve(self.try_resize(new_size));
+    }
+
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
+        if self.size == new_size{
+            return Ok(());
+        }
+
+        if self.element_layout.size() != 0 {
+            // Already allocated, so can't fail.
+            let mem_layout = self.mem_layout(self.size)?;
+
+            self.mem =
+                if new_size == 0 {
+                    unsafe{ self.allocator.deallocate(self.mem, mem_layout); }
+                    dangling(&self.element_layout)
+                } else {
+                    let new_mem_layout = self.mem_layout(new_size)?;
+                    if self.size == 0 {
+                        self.allocator.allocate(new_mem_layout)
+                    } else if new_size > self.size {
+                        unsafe{ self.allocator.grow(self.mem, mem_layout, new_mem_layout) }
+                    } else {
+                        unsafe{ self.allocator.shrink(self.mem, mem_layout, new_mem_layout) }
+                    }
+                    .map_err(|_| TryReserveError::AllocError{layout: new_mem_layout})?
+                    .cast::<u8>()
+                }
+        }
+        self.size = new_size;
+        Ok(())
+    }
+}
+
+impl<A: AllocatorApi> MemRawParts for AllocatorMem<A>{
+    type Handle = (NonNull<u8>, A);
+
+    #[inline]
+    fn into_raw_parts(self) -> (Self::Handle, Layout, usize) {
+        let this = ManuallyDrop::new(self);
+        let allocator = unsafe{ core::ptr::read(&this.allocator) };
+        ((this.mem, allocator), this.element_layout, this.size)
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts((mem, allocator): Self::Handle, element_layout: Layout, size: usize) -> Self {
+        Self{
+            mem,
+            size,
+            element_layout,
+            allocator
+        }
+    }
+}
+
+impl<A: AllocatorApi> Drop for AllocatorMem<A> {
+    fn drop(&mut self) {
+        self.resize(0);
+    }
+}
+
+unsafe impl<A: AllocatorApi + Send> Send for AllocatorMem<A>{}
+unsafe impl<A: AllocatorApi + Sync> Sync for AllocatorMem<A>{}
@@ -0,0 +1,259 @@
+extern crate alloc;
+
+use alloc::alloc::{alloc, dealloc};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::cell::{Cell, RefCell};
+use core::{cmp, ptr};
+use core::ptr::NonNull;
+use crate::mem::{dangling, handle_reserve, try_expand_with, Doubling, Mem, MemBuilder, MemBuilderSizeable, MemResizable, TryReserveError};
+
+/// Chunks alignment. Bigger alignment requires dedicated chunk.
+const CHUNK_ALIGN: usize = 16;
+
+struct ArenaRegion{
+    /// All allocated chunks. Last one - current.
+    chunks: RefCell<Vec<(NonNull<u8>, Layout)>>,
+    /// Current chunk start address.
+    start: Cell<usize>,
+    /// Current chunk free space start address.
+    cursor: Cell<usize>,
+    /// Current chunk end address.
+    end: Cell<usize>,
+    chunk_size: usize,
+}
+
+impl ArenaRegion{
+    fn alloc(&self, layout: Layout) -> Result<NonNull<u8>, TryReserveError> {
+        let start = self.cursor.get()
+            .checked_add(layout.align() - 1)
+            .ok_or(TryReserveError::CapacityExceeded)?
+            & !(layout.align() - 1);
+        if let Some(new_cursor) = start.checked_add(layout.size()) {
+            if new_cursor <= self.end.get() {
+                self.cursor.set(new_cursor);
+                return Ok(unsafe{ NonNull::new_unchecked(start as *mut u8) });
+            }
+        }
+
+        // New chunk
+        let chunk_layout = Layout::from_size_align(
+            cmp::max(self.chunk_size, layout.size()),
+            cmp::max(CHUNK_ALIGN, layout.align())
+        ).map_err(|_| TryReserveError::CapacityExceeded)?;
+        let chunk = NonNull::new(unsafe{ alloc(chunk_layout) })
+            .ok_or(TryReserveError::AllocError{layout: chunk_layout})?;
+        self.chunks.borrow_mut().push((chunk, chunk_layout));
+
+        let start = chunk.as_ptr() as usize;
+        self.start.set(start);
+        self.cursor.set(start + layout.size());
+        self.end.set(start + chunk_layout.size());
+        Ok(chunk)
+    }
+
+    /// Resize allocation in place, if it is the most recent one.
+    #[inline]
+    fn resize_tail(&self, ptr: NonNull<u8>, old_size: usize, new_size: usize) -> bool {
+        let start = ptr.as_ptr() as usize;
+        // Previous chunk allocation may end right at current chunk start.
+        if start < self.start.get() || start + old_size != self.cursor.get() {
+            return false;
+        }
+        match start.checked_add(new_size) {
+            Some(new_cursor) if new_cursor <= self.end.get() => {
+                self.cursor.set(new_cursor);
+                true
+            }
+            _ => false
+        }
+    }
+
+    fn reset(&self){
+        let mut chunks = self.chunks.borrow_mut();
+        // Keep current chunk.
+        let last = chunks.pop();
+        for (chunk, chunk_layout) in chunks.drain(..) {
+            unsafe{ dealloc(chunk.as_ptr(), chunk_layout); }
+        }
+        if let Some((chunk, chunk_layout)) = last {
+            chunks.push((chunk, chunk_layout));
+            self.cursor.set(chunk.as_ptr() as usize);
+        }
+    }
+}
+
+impl Drop for ArenaRegion{
+    fn drop(&mut self) {
+        for &(chunk, chunk_layout) in self.chunks.get_mut().iter() {
+            unsafe{ dealloc(chunk.as_ptr(), chunk_layout); }
+        }
+    }
+}
+
+/// Shared bump region memory.
+///
+/// Cloneable handle to one region. Many [`AnyVec`]s allocate from it,
+/// with bump allocation. Memory returned to region only when [`reset`].
+/// Most recent allocation grows and shrinks in place.
+///
+/// Region allocates memory with `chunk_size` chunks from global allocator.
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::mem::Arena;
+/// # use any_vec::traits::None;
+/// let mut arena = Arena::new(4096);
+/// for _ in 0..2 {
+///     {
+///         let mut v1: AnyVec<dyn None, Arena> = AnyVec::new_in::<u32>(arena.clone());
+///         let mut v2: AnyVec<dyn None, Arena> = AnyVec::new_in::<f32>(arena.clone());
+///         v1.downcast_mut::<u32>().unwrap().push(1);
+///         v2.downcast_mut::<f32>().unwrap().push(1.0);
+///     }
+///     arena.reset();
+/// }
+/// ```
+///
+/// [`AnyVec`]: crate::AnyVec
+/// [`reset`]: Self::reset
+#[derive(Clone)]
+pub struct Arena(Rc<ArenaRegion>);
+
+impl Arena{
+    /// Empty region, which will allocate memory in `chunk_size` bytes chunks.
+    #[inline]
+    pub fn new(chunk_size: usize) -> Self {
+        Self(Rc::new(ArenaRegion{
+            chunks: RefCell::new(Vec::new()),
+            start: Cell::new(0),
+            cursor: Cell::new(0),
+            end: Cell::new(0),
+            chunk_size
+        }))
+    }
+
+    /// Free all allocations at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if region is still in use by other handles (and [`AnyVec`]s).
+    ///
+    /// [`AnyVec`]: crate::AnyVec
+    pub fn reset(&mut self){
+        assert!(Rc::get_mut(&mut self.0).is_some(), "Arena is in use!");
+        self.0.reset();
+    }
+}
+
+impl MemBuilder for Arena{
+    /// Implements [`MemResizable`].
+    type Mem = ArenaMem;
+
+    #[inline]
+    fn build(&mut self, element_layout: Layout) -> Self::Mem {
+        ArenaMem{
+            arena: self.clone(),
+            mem: dangling(&element_layout),
+            size: 0,
+            element_layout
+        }
+    }
+}
+impl MemBuilderSizeable for Arena{
+    #[inline]
+    fn build_with_size(&mut self, element_layout: Layout, capacity: usize) -> Self::Mem {
+        let mut mem = self.build(element_layout);
+        mem.resize(capacity);
+        mem
+    }
+}
+
+pub struct ArenaMem{
+    arena: Arena,
+    mem: NonNull<u8>,
+    size: usize,        // in elements
+    element_layout: Layout, // size is aligned
+}
+
+impl Mem for ArenaMem{
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.mem.as_ptr()
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.mem.as_ptr()
+    }
+
+    #[inline]
+    fn element_layout(&self) -> Layout {
+        self.element_layout
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    fn expand(&mut self, additional: usize){
+        handle_reserve(self.try_expand(additional));
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError>{
+        try_expand_with(self, additional, &Doubling)
+    }
+}
+
+impl MemResizable for ArenaMem{
+    #[inline]
+    fn resize(&mut self, new_size: usize) {
+        handle_reserve(self.try_resize(new_size));
+    }
+
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
+        if self.size == new_size{
+            return Ok(());
+        }
+
+        let element_size = self.element_layout.size();
+        if element_size != 0 {
+            let region = &self.arena.0;
+            // Non checked mul, because this memory size already allocated.
+            let mem_size = element_size * self.size;
+            let new_mem_size = element_size.checked_mul(new_size)
+                .ok_or(TryReserveError::CapacityExceeded)?;
+
+            if self.size != 0 && region.resize_tail(self.mem, mem_size, new_mem_size) {
+                // in place
+            } else if new_mem_size < mem_size {
+                // Not in tail. Keep it as is, memory will be freed on reset.
+            } else {
+                let new_mem_layout = Layout::from_size_align(new_mem_size, self.element_layout.align())
+                    .map_err(|_| TryReserveError::CapacityExceeded)?;
+                let new_mem = region.alloc(new_mem_layout)?;
+                unsafe{
+                    ptr::copy_nonoverlapping(self.mem.as_ptr(), new_mem.as_ptr(), mem_size);
+                }
+                self.mem = new_mem;
+            }
+
+            if new_size == 0 {
+                self.mem = dangling(&self.element_layout);
+            }
+        }
+        self.size = new_size;
+        Ok(())
+    }
+}
+
+impl Drop for ArenaMem{
+    fn drop(&mut self) {
+        // Release tail, if possible.
+        self.resize(0);
+    }
+}
@@ -1,5 +1,5 @@
 use core::alloc::Layout;
-use crate::mem::{dangling, Mem, MemBuilder, MemRawParts};
+use crate::mem::{dangling, Mem, MemBuilder, MemRawParts, TryReserveError};
 
 /// Zero-size memory.
 ///
@@ -42,6 +42,10 @@ impl Mem for EmptyMem{
     fn size(&self) -> usize {
         0
     }
+    #[inline]
+    fn try_expand(&mut self, _: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::CapacityExceeded)
+    }
 }
 
 impl MemRawParts for EmptyMem{
@@ -0,0 +1,80 @@
+use core::alloc::Layout;
+use core::cmp;
+
+/// [`Mem::expand`] growth strategy.
+///
+/// Used by [`HeapWith`].
+///
+/// [`Mem::expand`]: super::Mem::expand
+/// [`HeapWith`]: super::HeapWith
+pub trait GrowthPolicy: Clone{
+    /// New size (in elements), for memory of `size` elements,
+    /// that must fit at least `requested_size` elements.
+    ///
+    /// Result less than `requested_size` is ignored.
+    fn grow(&self, size: usize, requested_size: usize, element_layout: Layout) -> usize;
+}
+
+/// Double size. Default.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct Doubling;
+impl GrowthPolicy for Doubling{
+    #[inline]
+    fn grow(&self, size: usize, requested_size: usize, _: Layout) -> usize {
+        cmp::max(size.saturating_mul(2), requested_size)
+    }
+}
+
+/// Grow exactly to requested size. Minimizes memory usage, at cost of
+/// frequent reallocations.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct Exact;
+impl GrowthPolicy for Exact{
+    #[inline]
+    fn grow(&self, _: usize, requested_size: usize, _: Layout) -> usize {
+        requested_size
+    }
+}
+
+/// Multiply size by factor. `Factor(1.5)` wastes less memory, then [`Doubling`].
+#[derive(Clone, Copy, Debug)]
+pub struct Factor(pub f64);
+impl GrowthPolicy for Factor{
+    #[inline]
+    fn grow(&self, size: usize, requested_size: usize, _: Layout) -> usize {
+        // float -> int cast saturates.
+        cmp::max((size as f64 * self.0) as usize, requested_size)
+    }
+}
+
+/// Do not allocate less than `min` elements.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct MinCapacity<P: GrowthPolicy = Doubling>{
+    pub min: usize,
+    pub policy: P,
+}
+impl<P: GrowthPolicy> GrowthPolicy for MinCapacity<P>{
+    #[inline]
+    fn grow(&self, size: usize, requested_size: usize, element_layout: Layout) -> usize {
+        cmp::max(self.policy.grow(size, requested_size, element_layout), self.min)
+    }
+}
+
+/// Round memory size up to `PAGE_SIZE` bytes.
+///
+/// For huge allocations, which go directly to OS pages anyway.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct PageRounded<P: GrowthPolicy = Doubling, const PAGE_SIZE: usize = 4096>(pub P);
+impl<P: GrowthPolicy, const PAGE_SIZE: usize> GrowthPolicy for PageRounded<P, PAGE_SIZE>{
+    #[inline]
+    fn grow(&self, size: usize, requested_size: usize, element_layout: Layout) -> usize {
+        let new_size = self.0.grow(size, requested_size, element_layout);
+        let element_size = element_layout.size();
+        if element_size == 0 {
+            return new_size;
+        }
+        new_size.checked_mul(element_size)
+            .and_then(|bytes| bytes.checked_next_multiple_of(PAGE_SIZE))
+            .map_or(new_size, |bytes| bytes / element_size)
+    }
+}
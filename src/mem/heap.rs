@@ -1,12 +1,14 @@
 extern crate alloc;
 
-use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout, realloc};
-use core::cmp;
+use alloc::alloc::{alloc, dealloc, Layout, realloc};
 use core::mem::ManuallyDrop;
+use core::ptr;
 use core::ptr::NonNull;
-use crate::mem::{dangling, Mem, MemBuilder, MemBuilderSizeable, MemRawParts, MemResizable};
+use crate::mem::{dangling, handle_reserve, try_expand_with, Doubling, GrowthPolicy, Mem, MemBuilder, MemBuilderSizeable, MemRawParts, MemResizable, TryReserveError};
 
 /// Heap allocated memory.
+///
+/// Grows with [`Doubling`]. See [`HeapWith`] for other [`GrowthPolicy`].
 #[derive(Default, Clone, Copy)]
 pub struct Heap;
 impl MemBuilder for Heap {
@@ -15,11 +17,7 @@ impl MemBuilder for Heap {
 
     #[inline]
     fn build(&mut self, element_layout: Layout) -> HeapMem {
-        HeapMem {
-            mem: dangling(&element_layout),
-            size: 0,
-            element_layout
-        }
+        HeapMem::new(element_layout, element_layout.align(), Doubling)
     }
 }
 impl MemBuilderSizeable for Heap{
@@ -32,13 +30,92 @@ impl MemBuilderSizeable for Heap{
     }
 }
 
-pub struct HeapMem {
+/// [`Heap`] with configurable growth.
+///
+/// `P` - [`GrowthPolicy`] of [`Mem::expand`]. Used by `reserve`, `push`, etc.
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::mem::{Factor, HeapWith};
+/// # use any_vec::traits::None;
+/// let mut any_vec: AnyVec<dyn None, HeapWith<Factor>> = AnyVec::new_in::<u64>(HeapWith(Factor(1.5)));
+/// any_vec.reserve(100);
+/// any_vec.downcast_mut::<u64>().unwrap().extend(0..101);
+/// assert_eq!(any_vec.capacity(), 150);
+/// ```
+#[derive(Default, Clone, Copy)]
+pub struct HeapWith<P: GrowthPolicy>(pub P);
+impl<P: GrowthPolicy> MemBuilder for HeapWith<P> {
+    /// Implements [`MemResizable`], [`MemRawParts`].
+    type Mem = HeapWithMem<P>;
+
+    #[inline]
+    fn build(&mut self, element_layout: Layout) -> Self::Mem {
+        HeapWithMem(HeapMem::new(element_layout, element_layout.align(), self.0.clone()))
+    }
+}
+impl<P: GrowthPolicy> MemBuilderSizeable for HeapWith<P>{
+    #[inline]
+    fn build_with_size(&mut self, element_layout: Layout, capacity: usize) -> Self::Mem
+    {
+        let mut mem = self.build(element_layout);
+        mem.resize(capacity);
+        mem
+    }
+}
+
+pub struct HeapMem<P: GrowthPolicy = Doubling> {
     mem: NonNull<u8>,
     size: usize,        // in elements
     element_layout: Layout, // size is aligned
+    align: usize,       // memory alignment, at least element_layout.align()
+    policy: P,
 }
 
-impl Mem for HeapMem {
+impl<P: GrowthPolicy> HeapMem<P> {
+    #[inline]
+    pub(crate) fn new(element_layout: Layout, align: usize, policy: P) -> Self {
+        let mut this = Self{
+            mem: NonNull::dangling(),
+            size: 0,
+            element_layout,
+            align,
+            policy
+        };
+        this.mem = this.dangling();
+        this
+    }
+
+    /// # Safety
+    ///
+    /// Same as [`MemRawParts::from_raw_parts`], `handle` allocated with `align`.
+    #[inline]
+    pub(crate) unsafe fn from_raw_parts_aligned(
+        handle: NonNull<u8>, element_layout: Layout, size: usize, align: usize, policy: P
+    ) -> Self {
+        Self{
+            mem: handle,
+            size,
+            element_layout,
+            align,
+            policy
+        }
+    }
+
+    #[inline]
+    pub(crate) fn into_raw_parts_with_policy(self) -> (NonNull<u8>, Layout, usize, P) {
+        let this = ManuallyDrop::new(self);
+        let policy = unsafe{ ptr::read(&this.policy) };
+        (this.mem, this.element_layout, this.size, policy)
+    }
+
+    #[inline]
+    fn dangling(&self) -> NonNull<u8> {
+        dangling(&unsafe{ Layout::from_size_align_unchecked(0, self.align) })
+    }
+}
+
+impl<P: GrowthPolicy> Mem for HeapMem<P> {
     #[inline]
     fn as_ptr(&self) -> *const u8 {
         self.mem.as_ptr()
@@ -59,17 +136,27 @@ impl Mem for HeapMem {
         self.size
     }
 
+    #[inline]
     fn expand(&mut self, additional: usize){
-        let requested_size = self.size() + additional;
-        let new_size = cmp::max(self.size() * 2, requested_size);
-        self.resize(new_size);
+        handle_reserve(self.try_expand(additional));
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError>{
+        let policy = self.policy.clone();
+        try_expand_with(self, additional, &policy)
     }
 }
 
-impl MemResizable for HeapMem {
+impl<P: GrowthPolicy> MemResizable for HeapMem<P> {
+    #[inline]
     fn resize(&mut self, new_size: usize) {
+        handle_reserve(self.try_resize(new_size));
+    }
+
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
         if self.size == new_size{
-            return;
+            return Ok(());
         }
 
         if self.element_layout.size() != 0 {
@@ -77,20 +164,21 @@ impl MemResizable for HeapMem {
                 // Non checked mul, because this memory size already allocated.
                 let mem_layout = Layout::from_size_align_unchecked(
                     self.element_layout.size() * self.size,
-                    self.element_layout.align()
+                    self.align
                 );
 
                 self.mem =
                     if new_size == 0 {
                         dealloc(self.mem.as_ptr(), mem_layout);
-                        dangling(&self.element_layout)
+                        self.dangling()
                     } else {
                         // mul carefully, to prevent overflow.
-                        let new_mem_size = self.element_layout.size()
-                            .checked_mul(new_size).unwrap();
-                        let new_mem_layout = Layout::from_size_align_unchecked(
-                            new_mem_size, self.element_layout.align()
-                        );
+                        let new_mem_layout = self.element_layout.size()
+                            .checked_mul(new_size)
+                            .and_then(|new_mem_size|
+                                Layout::from_size_align(new_mem_size, self.align).ok()
+                            )
+                            .ok_or(TryReserveError::CapacityExceeded)?;
 
                         if self.size == 0 {
                             // allocate
@@ -98,14 +186,15 @@ impl MemResizable for HeapMem {
                         } else {
                             // reallocate
                             NonNull::new(realloc(
-                                self.mem.as_ptr(), mem_layout,new_mem_size
+                                self.mem.as_ptr(), mem_layout, new_mem_layout.size()
                             ))
                         }
-                        .unwrap_or_else(|| handle_alloc_error(new_mem_layout))
+                        .ok_or(TryReserveError::AllocError{layout: new_mem_layout})?
                     }
             }
         }
         self.size = new_size;
+        Ok(())
     }
 }
 
@@ -114,25 +203,83 @@ impl MemRawParts for HeapMem{
 
     #[inline]
     fn into_raw_parts(self) -> (Self::Handle, Layout, usize) {
-        let this = ManuallyDrop::new(self);
-        (this.mem, this.element_layout, this.size)
+        let (mem, element_layout, size, _) = self.into_raw_parts_with_policy();
+        (mem, element_layout, size)
     }
 
     #[inline]
     unsafe fn from_raw_parts(handle: Self::Handle, element_layout: Layout, size: usize) -> Self {
-        Self{
-            mem: handle,
-            size,
-            element_layout
-        }
+        Self::from_raw_parts_aligned(handle, element_layout, size, element_layout.align(), Doubling)
     }
 }
 
-impl Drop for HeapMem {
+impl<P: GrowthPolicy> Drop for HeapMem<P> {
     fn drop(&mut self) {
         self.resize(0);
     }
 }
 
-unsafe impl Send for HeapMem{}
-unsafe impl Sync for HeapMem{}
\ No newline at end of file
+unsafe impl<P: GrowthPolicy + Send> Send for HeapMem<P>{}
+unsafe impl<P: GrowthPolicy + Sync> Sync for HeapMem<P>{}
+
+pub struct HeapWithMem<P: GrowthPolicy>(HeapMem<P>);
+
+impl<P: GrowthPolicy> Mem for HeapWithMem<P> {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_mut_ptr()
+    }
+
+    #[inline]
+    fn element_layout(&self) -> Layout {
+        self.0.element_layout()
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.0.size()
+    }
+
+    #[inline]
+    fn expand(&mut self, additional: usize) {
+        self.0.expand(additional)
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.0.try_expand(additional)
+    }
+}
+
+impl<P: GrowthPolicy> MemResizable for HeapWithMem<P> {
+    #[inline]
+    fn resize(&mut self, new_size: usize) {
+        self.0.resize(new_size)
+    }
+
+    #[inline]
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
+        self.0.try_resize(new_size)
+    }
+}
+
+/// Handle carries [`GrowthPolicy`].
+impl<P: GrowthPolicy> MemRawParts for HeapWithMem<P>{
+    type Handle = (NonNull<u8>, P);
+
+    #[inline]
+    fn into_raw_parts(self) -> (Self::Handle, Layout, usize) {
+        let (mem, element_layout, size, policy) = self.0.into_raw_parts_with_policy();
+        ((mem, policy), element_layout, size)
+    }
+
+    #[inline]
+    unsafe fn from_raw_parts((mem, policy): Self::Handle, element_layout: Layout, size: usize) -> Self {
+        Self(HeapMem::from_raw_parts_aligned(mem, element_layout, size, element_layout.align(), policy))
+    }
+}
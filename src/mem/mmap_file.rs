@@ -0,0 +1,315 @@
+extern crate std;
+
+use core::alloc::Layout;
+use core::any::type_name;
+use core::{cmp, ptr};
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use memmap2::{MmapMut, MmapOptions};
+use crate::{AnyVec, SatisfyTraits};
+use crate::mem::{handle_reserve, try_expand_with, Doubling, Mem, MemBuilder, MemResizable, TryReserveError};
+use crate::traits::Trait;
+
+const MAGIC: [u8; 8] = *b"ANYVEC\0\x01";
+
+/// File header. Data starts at [`data_offset`].
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Header{
+    magic: [u8; 8],
+    element_size: u64,
+    element_align: u64,
+    fingerprint: u64,
+    capacity: u64,
+}
+
+const HEADER_SIZE: usize = 64;
+
+#[inline]
+fn data_offset(element_layout: Layout) -> usize {
+    cmp::max(HEADER_SIZE, element_layout.align())
+}
+
+/// FNV-1a of type name.
+///
+/// `type_name` output is not guaranteed to be stable across compiler versions,
+/// so file may need to be reopened by the same compiler build.
+fn fingerprint(type_name: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in type_name.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+enum State{
+    /// Empty file, header not written yet.
+    Created(File),
+    /// Validated file with `capacity` elements.
+    Opened{file: File, map: MmapMut, capacity: usize, fingerprint: u64},
+}
+
+/// Memory-mapped file backed memory.
+///
+/// Growth enlarge file and remap it. Together with [`AnyVec::as_bytes`]
+/// allows to persist and reopen plain-old-data columns with zero copy.
+///
+/// File starts with header, containing element layout and type fingerprint
+/// (hash of type name, written by [`persist`]). They are checked on [`open`].
+/// Type name may differ between compiler versions - file tied to the compiler, that wrote it.
+///
+/// `Mem` does not know [`AnyVec`] length - file store capacity. Use [`persist`],
+/// to shrink capacity to length and flush to disk. [`open`] treats whole capacity as elements.
+///
+/// Only first [`build`] use the file. Other builds (like [`AnyVec::clone`])
+/// and [`Default`] constructed `MmapFile` use anonymous memory mapping.
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::mem::MmapFile;
+/// # use any_vec::traits::None;
+/// let path = std::env::temp_dir().join("any_vec_mmap_doc_test");
+/// {
+///     let mut any_vec: AnyVec<dyn None, MmapFile> = AnyVec::new_in::<u32>(MmapFile::create(&path).unwrap());
+///     any_vec.downcast_mut::<u32>().unwrap().extend([1, 2, 3]);
+///     MmapFile::persist(&mut any_vec).unwrap();
+/// }
+/// // Safety: file written above, with u32 elements.
+/// let any_vec: AnyVec<dyn None, MmapFile> = unsafe{ MmapFile::open::<u32, dyn None>(&path) }.unwrap();
+/// assert_eq!(any_vec.downcast_ref::<u32>().unwrap().as_slice(), &[1, 2, 3]);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// [`AnyVec::as_bytes`]: crate::AnyVec::as_bytes
+/// [`AnyVec::clone`]: crate::AnyVec::clone
+/// [`open`]: Self::open
+/// [`persist`]: Self::persist
+/// [`build`]: MemBuilder::build
+#[derive(Default, Clone)]
+pub struct MmapFile(Arc<Mutex<Option<State>>>);
+
+impl MmapFile{
+    /// Create new file, or truncate existing one.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true).write(true).create(true).truncate(true)
+            .open(path)?;
+        Ok(Self(Arc::new(Mutex::new(Some(State::Created(file))))))
+    }
+
+    /// Open file, previously filled with elements of type `T`.
+    ///
+    /// Return [`io::ErrorKind::InvalidData`], if file header does not match `T`.
+    ///
+    /// # Safety
+    ///
+    /// File bytes are used as `T` values as is. Header check does not validate data.
+    /// Every element in file must be valid `T` value. This holds, if `T` is plain-old-data -
+    /// without pointers and references, and with all bit patterns valid
+    /// (unlike `bool`, `char` or `NonZero*`).
+    pub unsafe fn open<T, Traits>(path: impl AsRef<Path>) -> io::Result<AnyVec<Traits, MmapFile>>
+    where
+        T: Copy + 'static + SatisfyTraits<Traits>,
+        Traits: ?Sized + Trait
+    {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let map = unsafe{ MmapMut::map_mut(&file)? };
+        if map.len() < HEADER_SIZE {
+            return Err(invalid_data("Not an AnyVec file!"));
+        }
+        let header = unsafe{ ptr::read_unaligned(map.as_ptr() as *const Header) };
+        if header.magic != MAGIC {
+            return Err(invalid_data("Not an AnyVec file!"));
+        }
+
+        let element_layout = Layout::new::<T>();
+        if header.element_size != element_layout.size() as u64
+            || header.element_align != element_layout.align() as u64
+        {
+            return Err(invalid_data("Element layout mismatch!"));
+        }
+        if header.fingerprint != fingerprint(type_name::<T>()) {
+            return Err(invalid_data("Element type mismatch!"));
+        }
+
+        let capacity = usize::try_from(header.capacity)
+            .map_err(|_| invalid_data("File corrupted!"))?;
+        let data_size = element_layout.size().checked_mul(capacity)
+            .ok_or_else(|| invalid_data("File corrupted!"))?;
+        if map.len() != data_offset(element_layout) + data_size {
+            return Err(invalid_data("File corrupted!"));
+        }
+
+        let mem_builder = Self(Arc::new(Mutex::new(Some(State::Opened{
+            file, map, capacity, fingerprint: header.fingerprint
+        }))));
+        let mut any_vec = AnyVec::new_in::<T>(mem_builder);
+        unsafe{ any_vec.set_len(capacity); }
+        Ok(any_vec)
+    }
+
+    /// Shrink capacity to length, write type fingerprint and flush to disk.
+    pub fn persist<Traits: ?Sized + Trait>(any_vec: &mut AnyVec<Traits, MmapFile>) -> io::Result<()> {
+        any_vec.shrink_to_fit();
+        let fingerprint = fingerprint(any_vec.element_type_name());
+        let mem = &mut any_vec.raw.mem;
+        mem.fingerprint = fingerprint;
+        mem.write_header();
+        mem.map.flush()
+    }
+}
+
+impl MemBuilder for MmapFile{
+    /// Implements [`MemResizable`].
+    type Mem = MmapFileMem;
+
+    fn build(&mut self, element_layout: Layout) -> Self::Mem {
+        let state = self.0.lock().unwrap().take();
+        match state {
+            Some(State::Opened{file, map, capacity, fingerprint}) => MmapFileMem{
+                file: Some(file),
+                map,
+                size: capacity,
+                element_layout,
+                fingerprint,
+            },
+            Some(State::Created(file)) => MmapFileMem::new(Some(file), element_layout),
+            None => MmapFileMem::new(None, element_layout),
+        }
+    }
+}
+
+pub struct MmapFileMem{
+    file: Option<File>,     // None - anonymous
+    map: MmapMut,           // header + data
+    size: usize,            // in elements
+    element_layout: Layout,
+    fingerprint: u64,
+}
+
+impl MmapFileMem{
+    fn new(file: Option<File>, element_layout: Layout) -> Self {
+        let len = data_offset(element_layout);
+        let map = match &file {
+            Some(file) => file.set_len(len as u64)
+                .and_then(|_| unsafe{ MmapMut::map_mut(file) }),
+            None => MmapMut::map_anon(len),
+        }.expect("Can't map memory!");
+
+        let mut this = Self{ file, map, size: 0, element_layout, fingerprint: 0 };
+        this.write_header();
+        this
+    }
+
+    #[inline]
+    fn write_header(&mut self){
+        let header = Header{
+            magic: MAGIC,
+            element_size: self.element_layout.size() as u64,
+            element_align: self.element_layout.align() as u64,
+            fingerprint: self.fingerprint,
+            capacity: self.size as u64,
+        };
+        unsafe{ ptr::write_unaligned(self.map.as_mut_ptr() as *mut Header, header); }
+    }
+}
+
+impl Mem for MmapFileMem{
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        unsafe{ self.map.as_ptr().add(data_offset(self.element_layout)) }
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        unsafe{ self.map.as_mut_ptr().add(data_offset(self.element_layout)) }
+    }
+
+    #[inline]
+    fn element_layout(&self) -> Layout {
+        self.element_layout
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline]
+    fn expand(&mut self, additional: usize){
+        handle_reserve(self.try_expand(additional));
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError>{
+        try_expand_with(self, additional, &Doubling)
+    }
+}
+
+impl MemResizable for MmapFileMem{
+    #[inline]
+    fn resize(&mut self, new_size: usize) {
+        handle_reserve(self.try_resize(new_size));
+    }
+
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
+        if self.size == new_size{
+            return Ok(());
+        }
+
+        let offset = data_offset(self.element_layout);
+        let new_len = self.element_layout.size()
+            .checked_mul(new_size)
+            .and_then(|data_size| data_size.checked_add(offset))
+            .filter(|&len| len <= isize::MAX as usize)
+            .ok_or(TryReserveError::CapacityExceeded)?;
+        let alloc_error = TryReserveError::AllocError{
+            layout: unsafe{ Layout::from_size_align_unchecked(new_len, offset) }
+        };
+
+        self.map = match &self.file {
+            // Mapping must never be longer than file - access past file end is SIGBUS.
+            Some(file) => if new_len > self.map.len() {
+                // Grow file, then map. Restore length, if mapping fails.
+                file.set_len(new_len as u64).map_err(|_| alloc_error)?;
+                match unsafe{ MmapOptions::new().len(new_len).map_mut(file) } {
+                    Ok(map) => map,
+                    Err(_) => {
+                        let _ = file.set_len(self.map.len() as u64);
+                        return Err(alloc_error);
+                    }
+                }
+            } else {
+                // Map shorter, then shrink file.
+                let map = unsafe{ MmapOptions::new().len(new_len).map_mut(file) }
+                    .map_err(|_| alloc_error)?;
+                file.set_len(new_len as u64).map_err(|_| alloc_error)?;
+                map
+            }
+            None => {
+                let mut map = MmapMut::map_anon(new_len).map_err(|_| alloc_error)?;
+                let len = cmp::min(self.map.len(), new_len);
+                map[..len].copy_from_slice(&self.map[..len]);
+                map
+            }
+        };
+        self.size = new_size;
+        self.write_header();
+        Ok(())
+    }
+}
+
+impl Drop for MmapFileMem{
+    fn drop(&mut self) {
+        if self.file.is_some() {
+            let _ = self.map.flush();
+        }
+    }
+}
@@ -1,15 +1,42 @@
 #[cfg(feature="alloc")]
 mod heap;
+#[cfg(feature="alloc")]
+mod aligned_heap;
+#[cfg(feature="alloc")]
+mod small_heap;
+#[cfg(feature="alloc")]
+mod arena;
+#[cfg(feature="allocator_api2")]
+mod allocator;
+#[cfg(feature="mmap")]
+mod mmap_file;
 mod stack;
 mod stack_n;
 mod empty;
+mod growth_policy;
 
 #[cfg(feature="alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use heap::Heap;
+pub use heap::{Heap, HeapWith};
+#[cfg(feature="alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use aligned_heap::AlignedHeap;
+#[cfg(feature="alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use small_heap::SmallHeap;
+#[cfg(feature="alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use arena::Arena;
+#[cfg(feature="allocator_api2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "allocator_api2")))]
+pub use allocator::Allocator;
+#[cfg(feature="mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+pub use mmap_file::MmapFile;
 pub use stack::Stack;
 pub use stack_n::StackN;
 pub use empty::Empty;
+pub use growth_policy::{GrowthPolicy, Doubling, Exact, Factor, MinCapacity, PageRounded};
 
 #[cfg(feature="alloc")]
 pub(crate) type Default = Heap;
@@ -17,11 +44,79 @@ pub(crate) type Default = Heap;
 pub(crate) type Default = Empty;
 
 use core::alloc::Layout;
+use core::fmt;
+use core::fmt::{Display, Formatter};
 use core::ptr::NonNull;
 
+/// [`Mem`] growth failure.
+///
+/// Returned by fallible operations, like [`AnyVec::try_reserve`].
+///
+/// [`AnyVec::try_reserve`]: crate::AnyVec::try_reserve
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TryReserveError{
+    /// [`Mem`] can not hold requested number of elements.
+    /// Either it is fixed capacity, or requested size overflows.
+    CapacityExceeded,
+    /// Memory allocator returned an error.
+    AllocError{
+        /// Layout of failed allocation.
+        layout: Layout
+    },
+}
+
+impl Display for TryReserveError{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityExceeded =>
+                f.write_str("memory capacity exceeded"),
+            TryReserveError::AllocError{layout} =>
+                write!(f, "memory allocation of {} bytes failed", layout.size()),
+        }
+    }
+}
+
+impl core::error::Error for TryReserveError{}
+
+/// [`TryReserveError`] with the value, which could not be inserted.
+///
+/// Returned by [`AnyVec::try_push`], [`AnyVec::try_insert`], etc.
+///
+/// [`AnyVec::try_push`]: crate::AnyVec::try_push
+/// [`AnyVec::try_insert`]: crate::AnyVec::try_insert
+pub struct TryInsertError<V>{
+    pub error: TryReserveError,
+    pub value: V,
+}
+
+impl<V> TryInsertError<V>{
+    /// Take value back.
+    #[inline]
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+impl<V> fmt::Debug for TryInsertError<V>{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryInsertError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V> Display for TryInsertError<V>{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<V> core::error::Error for TryInsertError<V>{}
+
 /// This is [`Mem`] builder.
 ///
-/// It can be stateful. You can use it like Allocator.
+/// It can be stateful. You can use it like Allocator. See [`Arena`] for stateful builder.
 /// Making `MemBuilder` default constructible, allow to use [`AnyVec::new`], without that you
 /// limited to [`AnyVec::new_in`].
 ///
@@ -78,14 +173,22 @@ pub trait Mem{
         panic!("Can't change capacity!");
 
         /*let requested_size = self.size() + additional;
-        let new_size = cmp::max(self.size() * 2, requested_size);
+        let new_size = core::cmp::max(self.size() * 2, requested_size);
         self.resize(new_size);*/
     }
+
+    /// Fallible [`expand`]. Must not panic on failure.
+    ///
+    /// Fixed capacity `Mem` should return [`TryReserveError::CapacityExceeded`].
+    /// Resizable one - do the same as [`expand`].
+    ///
+    /// [`expand`]: Self::expand
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError>;
 }
 
 /// Resizable [`Mem`].
 ///
-/// Implemented by [`Heap::Mem`].
+/// Implemented by [`Heap::Mem`], [`HeapWith::Mem`], [`AlignedHeap::Mem`], [`SmallHeap::Mem`], [`Arena::Mem`], [`Allocator::Mem`], [`MmapFile::Mem`].
 pub trait MemResizable: Mem{
     /// Expand `Mem` size for **exactly** `additional` more elements.
     /// Implementation encouraged to be as precise as possible with new memory size.
@@ -103,11 +206,25 @@ pub trait MemResizable: Mem{
     ///
     /// Implementation may panic, if fail to allocate/reallocate/deallocate memory.
     fn resize(&mut self, new_size: usize);
+
+    /// Fallible [`expand_exact`]. Must not panic on failure.
+    ///
+    /// [`expand_exact`]: Self::expand_exact
+    fn try_expand_exact(&mut self, additional: usize) -> Result<(), TryReserveError>{
+        let new_size = self.size().checked_add(additional)
+            .ok_or(TryReserveError::CapacityExceeded)?;
+        self.try_resize(new_size)
+    }
+
+    /// Fallible [`resize`]. Must not panic on failure.
+    ///
+    /// [`resize`]: Self::resize
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError>;
 }
 
 /// [`Mem`] destructurable into raw parts.
 ///
-/// Implemented by [`Heap::Mem`], [`Empty::Mem`].
+/// Implemented by [`Heap::Mem`], [`HeapWith::Mem`], [`AlignedHeap::Mem`], [`Allocator::Mem`], [`Empty::Mem`].
 pub trait MemRawParts: Mem{
     type Handle;
 
@@ -116,7 +233,7 @@ pub trait MemRawParts: Mem{
 }
 
 #[inline]
-const fn dangling(layout: &Layout) -> NonNull<u8>{
+pub(crate) const fn dangling(layout: &Layout) -> NonNull<u8>{
     #[cfg(miri)]
     {
         layout.dangling()
@@ -125,4 +242,37 @@ const fn dangling(layout: &Layout) -> NonNull<u8>{
     {
         unsafe { NonNull::new_unchecked(layout.align() as *mut u8) }
     }
-}
\ No newline at end of file
+}
+
+/// Panic on [`TryReserveError`], like `Vec` does.
+#[cfg(any(feature="alloc", feature="allocator_api2", feature="mmap"))]
+#[inline]
+pub(crate) fn handle_reserve(result: Result<(), TryReserveError>){
+    match result {
+        Ok(()) => {},
+        Err(TryReserveError::CapacityExceeded) => panic!("Capacity overflow!"),
+        #[cfg(feature="alloc")]
+        Err(TryReserveError::AllocError{layout}) => {
+            extern crate alloc;
+            alloc::alloc::handle_alloc_error(layout)
+        }
+        #[cfg(not(feature="alloc"))]
+        Err(TryReserveError::AllocError{..}) => panic!("Allocation failed!"),
+    }
+}
+
+/// [`Mem::try_expand`] for [`MemResizable`], with `policy` growth.
+#[cfg(any(feature="alloc", feature="allocator_api2", feature="mmap"))]
+#[inline]
+pub(crate) fn try_expand_with<M: MemResizable, P: GrowthPolicy>(
+    mem: &mut M, additional: usize, policy: &P
+) -> Result<(), TryReserveError> {
+    let size = mem.size();
+    let requested_size = size.checked_add(additional)
+        .ok_or(TryReserveError::CapacityExceeded)?;
+    let new_size = core::cmp::max(
+        policy.grow(size, requested_size, mem.element_layout()),
+        requested_size
+    );
+    mem.try_resize(new_size)
+}
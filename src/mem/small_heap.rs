@@ -0,0 +1,154 @@
+extern crate alloc;
+
+use core::alloc::Layout;
+use core::ptr;
+use crate::mem::{handle_reserve, try_expand_with, Doubling, Heap, Mem, MemBuilder, MemBuilderSizeable, MemResizable, TryReserveError};
+use crate::mem::heap::HeapMem;
+use crate::any_value::inline::{InlineStorage, INLINE_ALIGN};
+
+/// Small-buffer-optimized memory.
+///
+/// Store up to `SIZE` bytes inline, moves to heap when out of capacity.
+/// Moves back inline, when shrinked enough (with [`shrink_to_fit`], for example).
+///
+/// Elements with alignment greater than 16 always stored in heap.
+///
+/// ```rust
+/// # use any_vec::AnyVec;
+/// # use any_vec::mem::SmallHeap;
+/// # use any_vec::traits::None;
+/// type SmallAnyVec<Traits = dyn None> = AnyVec<Traits, SmallHeap<32>>;
+/// let mut any_vec: SmallAnyVec = AnyVec::new::<u64>();
+/// any_vec.downcast_mut::<u64>().unwrap().extend([1, 2, 3, 4]); // inline
+/// any_vec.downcast_mut::<u64>().unwrap().push(5);              // heap
+/// ```
+///
+/// [`shrink_to_fit`]: crate::AnyVec::shrink_to_fit
+#[derive(Default, Clone, Copy)]
+pub struct SmallHeap<const SIZE: usize>;
+impl<const SIZE: usize> MemBuilder for SmallHeap<SIZE>{
+    /// Implements [`MemResizable`].
+    type Mem = SmallHeapMem<SIZE>;
+
+    #[inline]
+    fn build(&mut self, element_layout: Layout) -> Self::Mem {
+        let inline_size =
+            if element_layout.size() == 0{
+                usize::MAX
+            } else if element_layout.align() > INLINE_ALIGN {
+                0
+            } else {
+                SIZE / element_layout.size()
+            };
+
+        SmallHeapMem{
+            inline: InlineStorage::uninit(),
+            inline_size,
+            heap: Heap.build(element_layout)
+        }
+    }
+}
+impl<const SIZE: usize> MemBuilderSizeable for SmallHeap<SIZE>{
+    #[inline]
+    fn build_with_size(&mut self, element_layout: Layout, capacity: usize) -> Self::Mem {
+        let mut mem = self.build(element_layout);
+        mem.resize(capacity);
+        mem
+    }
+}
+
+pub struct SmallHeapMem<const SIZE: usize>{
+    inline: InlineStorage<SIZE>,
+    inline_size: usize,     // in elements
+    heap: HeapMem,          // empty, if inline
+}
+
+impl<const SIZE: usize> SmallHeapMem<SIZE>{
+    /// Over-aligned elements (`inline_size == 0`) always in heap,
+    /// even when empty - to have properly aligned pointer.
+    #[inline]
+    fn is_inline(&self) -> bool {
+        self.inline_size != 0 && self.heap.size() == 0
+    }
+}
+
+impl<const SIZE: usize> Mem for SmallHeapMem<SIZE>{
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        if self.is_inline(){
+            self.inline.as_ptr()
+        } else {
+            self.heap.as_ptr()
+        }
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        if self.is_inline(){
+            self.inline.as_mut_ptr()
+        } else {
+            self.heap.as_mut_ptr()
+        }
+    }
+
+    #[inline]
+    fn element_layout(&self) -> Layout {
+        self.heap.element_layout()
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        if self.is_inline(){
+            self.inline_size
+        } else {
+            self.heap.size()
+        }
+    }
+
+    #[inline]
+    fn expand(&mut self, additional: usize){
+        handle_reserve(self.try_expand(additional));
+    }
+
+    #[inline]
+    fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError>{
+        try_expand_with(self, additional, &Doubling)
+    }
+}
+
+impl<const SIZE: usize> MemResizable for SmallHeapMem<SIZE>{
+    #[inline]
+    fn resize(&mut self, new_size: usize) {
+        handle_reserve(self.try_resize(new_size));
+    }
+
+    fn try_resize(&mut self, new_size: usize) -> Result<(), TryReserveError> {
+        let element_size = self.element_layout().size();
+        if new_size <= self.inline_size {
+            if !self.is_inline(){
+                // move back inline
+                unsafe{
+                    ptr::copy_nonoverlapping(
+                        self.heap.as_ptr(),
+                        self.inline.as_mut_ptr(),
+                        new_size * element_size
+                    );
+                }
+                self.heap.try_resize(0)?;
+            }
+        } else if self.is_inline() {
+            // spill
+            self.heap.try_resize(new_size)?;
+            unsafe{
+                ptr::copy_nonoverlapping(
+                    self.inline.as_ptr(),
+                    self.heap.as_mut_ptr(),
+                    self.inline_size * element_size
+                );
+            }
+        } else {
+            self.heap.try_resize(new_size)?;
+        }
+        Ok(())
+    }
+}
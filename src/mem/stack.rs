@@ -1,6 +1,6 @@
 use core::alloc::Layout;
 use core::mem::MaybeUninit;
-use crate::mem::{Mem, MemBuilder};
+use crate::mem::{Mem, MemBuilder, TryReserveError};
 
 /// Fixed `SIZE` capacity on-stack memory.
 ///
@@ -58,4 +58,8 @@ impl<const SIZE: usize> Mem for StackMem<SIZE>{
     fn size(&self) -> usize {
         self.size
     }
+    #[inline]
+    fn try_expand(&mut self, _: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::CapacityExceeded)
+    }
 }
\ No newline at end of file
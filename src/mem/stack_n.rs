@@ -1,6 +1,6 @@
 use core::alloc::Layout;
 use core::mem::MaybeUninit;
-use crate::mem::{Mem, MemBuilder};
+use crate::mem::{Mem, MemBuilder, TryReserveError};
 
 /// Fixed `SIZE` capacity on-stack memory for `N` elements.
 ///
@@ -51,4 +51,8 @@ impl<const N:usize, const SIZE: usize> Mem for StackNMem<N, SIZE>{
     fn size(&self) -> usize {
         N
     }
+    #[inline]
+    fn try_expand(&mut self, _: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::CapacityExceeded)
+    }
 }
\ No newline at end of file
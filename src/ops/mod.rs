@@ -5,12 +5,14 @@
 //! [AnyVec]: crate::AnyVec
 
 mod temp;
-mod iter;
+pub(crate) mod iter;
 pub(crate) mod swap_remove;
 pub(crate) mod remove;
 pub(crate) mod drain;
 pub(crate) mod splice;
 pub(crate) mod pop;
+pub(crate) mod retain;
+pub(crate) mod sort;
 
 pub use temp::TempValue;
 pub use iter::Iter;
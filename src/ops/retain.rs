@@ -0,0 +1,79 @@
+use crate::any_vec_ptr::IAnyVecRawPtr;
+use crate::any_vec_ptr::utils::{drop_elements_range, element_mut_ptr_at, move_elements_at};
+
+/// Retain progress. Shift unprocessed tail back and restore len on destruction,
+/// even if predicate or element drop panics.
+struct Retain<AnyVecPtr: IAnyVecRawPtr>{
+    any_vec_ptr: AnyVecPtr,
+    processed: usize,
+    deleted: usize,
+    original_len: usize
+}
+
+impl<AnyVecPtr: IAnyVecRawPtr> Drop for Retain<AnyVecPtr>{
+    #[inline]
+    fn drop(&mut self) {
+        unsafe{
+            // 1. mem move unprocessed elements
+            if self.deleted > 0 {
+                move_elements_at(
+                    self.any_vec_ptr,
+                    self.processed,
+                    self.processed - self.deleted,
+                    self.original_len - self.processed
+                );
+            }
+
+            // 2. len
+            let any_vec_raw = self.any_vec_ptr.any_vec_raw_mut();
+            any_vec_raw.len = self.original_len - self.deleted;
+        }
+    }
+}
+
+/// Keep only elements for which `f(element_ptr)` return `true`.
+/// Discarded elements dropped in place, retained - shifted left.
+///
+/// Visit each element exactly once, in the original order.
+#[inline]
+pub(crate) fn retain<AnyVecPtr: IAnyVecRawPtr>(
+    mut any_vec_ptr: AnyVecPtr,
+    mut f: impl FnMut(*mut u8) -> bool
+) {
+    let any_vec_raw = unsafe{ any_vec_ptr.any_vec_raw_mut() };
+    let original_len = any_vec_raw.len;
+
+    // mem::forget and element drop panic "safety".
+    any_vec_raw.len = 0;
+
+    let mut guard = Retain{
+        any_vec_ptr,
+        processed: 0,
+        deleted: 0,
+        original_len
+    };
+
+    while guard.processed != original_len {
+        let element = unsafe{ element_mut_ptr_at(any_vec_ptr, guard.processed) };
+        if !f(element) {
+            // Advance first, so if drop panics - element will not be touched again.
+            guard.processed += 1;
+            guard.deleted += 1;
+            unsafe{
+                drop_elements_range(any_vec_ptr, guard.processed - 1, guard.processed);
+            }
+        } else {
+            if guard.deleted > 0 {
+                unsafe{
+                    move_elements_at(
+                        any_vec_ptr,
+                        guard.processed,
+                        guard.processed - guard.deleted,
+                        1
+                    );
+                }
+            }
+            guard.processed += 1;
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use core::cmp::Ordering;
+use core::ptr;
+
+/// In-place, allocation-free sort of type-erased elements.
+///
+/// Elements permuted with byte swaps only, so vector always contains
+/// valid elements - even if `cmp` panics.
+struct Sorter<F: FnMut(*const u8, *const u8) -> Ordering>{
+    ptr: *mut u8,
+    size: usize,
+    cmp: F
+}
+
+impl<F: FnMut(*const u8, *const u8) -> Ordering> Sorter<F>{
+    #[inline]
+    fn at(&self, i: usize) -> *mut u8 {
+        unsafe{ self.ptr.add(i * self.size) }
+    }
+
+    #[inline]
+    fn less(&mut self, i: usize, j: usize) -> bool {
+        let (lhs, rhs) = (self.at(i), self.at(j));
+        (self.cmp)(lhs, rhs) == Ordering::Less
+    }
+
+    #[inline]
+    fn swap(&mut self, i: usize, j: usize){
+        if i != j {
+            unsafe{ ptr::swap_nonoverlapping(self.at(i), self.at(j), self.size); }
+        }
+    }
+
+    fn reverse(&mut self, mut a: usize, mut b: usize){
+        while a + 1 < b {
+            b -= 1;
+            self.swap(a, b);
+            a += 1;
+        }
+    }
+
+    /// Rotate [a, b) so element `m` becomes first.
+    fn rotate(&mut self, a: usize, m: usize, b: usize){
+        self.reverse(a, m);
+        self.reverse(m, b);
+        self.reverse(a, b);
+    }
+
+    fn insertion_sort(&mut self, a: usize, b: usize){
+        for i in a+1..b {
+            let mut j = i;
+            while j > a && self.less(j, j-1) {
+                self.swap(j, j-1);
+                j -= 1;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut root: usize, end: usize){
+        loop {
+            let mut child = 2 * root + 1;
+            if child >= end {
+                return;
+            }
+            if child + 1 < end && self.less(child, child + 1) {
+                child += 1;
+            }
+            if !self.less(root, child) {
+                return;
+            }
+            self.swap(root, child);
+            root = child;
+        }
+    }
+
+    fn heap_sort(&mut self, len: usize){
+        for i in (0..len/2).rev() {
+            self.sift_down(i, len);
+        }
+        for end in (1..len).rev() {
+            self.swap(0, end);
+            self.sift_down(0, end);
+        }
+    }
+
+    /// Merge sorted [a, m) and [m, b) in place. SymMerge algorithm.
+    fn sym_merge(&mut self, a: usize, m: usize, b: usize){
+        if m - a == 1 {
+            // insert `a` into [m, b)
+            let (mut i, mut j) = (m, b);
+            while i < j {
+                let h = (i + j) / 2;
+                if self.less(h, a) { i = h + 1; } else { j = h; }
+            }
+            for k in a..i-1 {
+                self.swap(k, k + 1);
+            }
+            return;
+        }
+        if b - m == 1 {
+            // insert `m` into [a, m)
+            let (mut i, mut j) = (a, m);
+            while i < j {
+                let h = (i + j) / 2;
+                if !self.less(m, h) { i = h + 1; } else { j = h; }
+            }
+            for k in (i+1..=m).rev() {
+                self.swap(k, k - 1);
+            }
+            return;
+        }
+
+        let mid = (a + b) / 2;
+        let n = mid + m;
+        let (mut start, mut r) = if m > mid { (n - b, mid) } else { (a, m) };
+        let p = n - 1;
+        while start < r {
+            let c = (start + r) / 2;
+            if !self.less(p - c, c) { start = c + 1; } else { r = c; }
+        }
+        let end = n - start;
+        if start < m && m < end {
+            self.rotate(start, m, end);
+        }
+        if a < start && start < mid {
+            self.sym_merge(a, start, mid);
+        }
+        if mid < end && end < b {
+            self.sym_merge(mid, end, b);
+        }
+    }
+
+    fn stable_sort(&mut self, len: usize){
+        const BLOCK_SIZE: usize = 20;
+
+        let mut a = 0;
+        while a + BLOCK_SIZE <= len {
+            self.insertion_sort(a, a + BLOCK_SIZE);
+            a += BLOCK_SIZE;
+        }
+        self.insertion_sort(a, len);
+
+        let mut block_size = BLOCK_SIZE;
+        while block_size < len {
+            let mut a = 0;
+            while a + 2*block_size <= len {
+                self.sym_merge(a, a + block_size, a + 2*block_size);
+                a += 2*block_size;
+            }
+            if a + block_size < len {
+                self.sym_merge(a, a + block_size, len);
+            }
+            block_size *= 2;
+        }
+    }
+}
+
+/// Stable sort of `len` elements of `size` bytes, starting at `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` valid elements of `size` bytes each.
+#[inline]
+pub(crate) unsafe fn sort(
+    ptr: *mut u8, size: usize, len: usize,
+    cmp: impl FnMut(*const u8, *const u8) -> Ordering
+) {
+    Sorter{ptr, size, cmp}.stable_sort(len);
+}
+
+/// Unstable sort of `len` elements of `size` bytes, starting at `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` valid elements of `size` bytes each.
+#[inline]
+pub(crate) unsafe fn sort_unstable(
+    ptr: *mut u8, size: usize, len: usize,
+    cmp: impl FnMut(*const u8, *const u8) -> Ordering
+) {
+    Sorter{ptr, size, cmp}.heap_sort(len);
+}
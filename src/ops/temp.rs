@@ -1,10 +1,13 @@
 use core::any::TypeId;
-use core::{mem, ptr};
-use crate::any_value::{AnyValue, AnyValueCloneable, AnyValueMut, AnyValueSizeless, AnyValueSizelessMut, AnyValueTypeless, AnyValueTypelessMut, Unknown};
+use core::{fmt, mem, ptr};
+use core::fmt::{Debug, Formatter};
+#[cfg(feature="alloc")]
+use crate::any_value::AnyValueBox;
+use crate::any_value::{AnyValue, AnyValueCloneable, AnyValueInline, AnyValueMut, AnyValueSizeless, AnyValueSizelessMut, AnyValueTypeless, AnyValueTypelessMut, Unknown};
 use crate::any_vec_raw::AnyVecRaw;
 use crate::any_vec_ptr::{IAnyVecPtr, IAnyVecRawPtr};
 use crate::{AnyVec, copy_nonoverlapping_value};
-use crate::traits::Cloneable;
+use crate::traits::{Cloneable, Debuggable};
 
 pub trait Operation {
     type AnyVecPtr: IAnyVecRawPtr;
@@ -46,6 +49,33 @@ impl<Op: Operation> TempValue<Op>{
             mem::size_of::<<Op::AnyVecPtr as IAnyVecRawPtr>::Element>()
         }
     }
+
+    /// Move value to heap, releasing `&mut AnyVec`.
+    #[cfg(feature="alloc")]
+    #[inline]
+    pub fn into_box(mut self) -> AnyValueBox<<Op::AnyVecPtr as IAnyVecPtr>::Traits>
+    where
+        Op::AnyVecPtr: IAnyVecPtr
+    {
+        let boxed = unsafe{ AnyValueBox::from_raw(self.as_bytes_ptr(), self.any_vec_raw().vtable) };
+        self.op.consume();
+        mem::forget(self);
+        boxed
+    }
+
+    /// Move value to [`AnyValueInline`], releasing `&mut AnyVec`.
+    ///
+    /// Return `self` back, if value does not [`fit`].
+    ///
+    /// [`fit`]: AnyValueInline::fits
+    #[inline]
+    pub fn into_inline<const SIZE: usize>(self) -> Result<AnyValueInline<SIZE, <Op::AnyVecPtr as IAnyVecPtr>::Traits>, Self>
+    where
+        Op::AnyVecPtr: IAnyVecPtr
+    {
+        let vtable = self.any_vec_raw().vtable;
+        unsafe{ AnyValueInline::try_from_any_value(self, vtable) }
+    }
 }
 
 impl<Op: Operation> AnyValueSizeless for TempValue<Op> {
@@ -81,11 +111,16 @@ impl<Op: Operation> AnyValue for TempValue<Op>{
     fn value_typeid(&self) -> TypeId {
         let typeid = TypeId::of::<Self::Type>();
         if typeid == TypeId::of::<Unknown>(){
-            self.any_vec_raw().type_id
+            self.any_vec_raw().vtable.type_id
         } else {
             typeid
         }
     }
+
+    #[inline]
+    fn value_type_name(&self) -> &'static str {
+        (self.any_vec_raw().vtable.type_name)()
+    }
 }
 
 impl<Op: Operation> AnyValueTypelessMut for TempValue<Op> {}
@@ -98,16 +133,28 @@ where
 {
     #[inline]
     unsafe fn clone_into(&self, out: *mut u8) {
-        let clone_fn = self.op.any_vec_ptr().any_vec().clone_fn();
+        let clone_fn = self.op.any_vec_ptr().any_vec().element_clone();
         (clone_fn)(self.as_bytes().as_ptr(), out, 1);
     }
 }
 
+impl<Op: Operation> Debug for TempValue<Op>
+where
+    Op::AnyVecPtr: IAnyVecPtr,
+    <Op::AnyVecPtr as IAnyVecPtr>::Traits: Debuggable
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let debug_fn = unsafe{ self.op.any_vec_ptr().any_vec() }.element_debug();
+        unsafe{ (debug_fn)(self.as_bytes_ptr(), f) }
+    }
+}
+
 impl<Op: Operation> Drop for TempValue<Op>{
     #[inline]
     fn drop(&mut self) {
         unsafe{
-            let drop_fn = self.any_vec_raw().drop_fn;
+            let drop_fn = self.any_vec_raw().vtable.drop;
             let element = self.op.bytes() as *mut u8;
 
             // compile-time check
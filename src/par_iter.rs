@@ -0,0 +1,296 @@
+//! [`rayon`] parallel iterators.
+
+use core::ops::{Range, RangeBounds};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelDrainRange, ParallelIterator};
+use crate::any_vec_ptr::{AnyVecPtr, IAnyVecRawPtr};
+use crate::any_vec_ptr::utils::drop_elements_range;
+use crate::element::{Element, ElementMut, ElementPointer, ElementRef};
+use crate::iter::{ElementMutIterItem, ElementRefIterItem, Iter, IteratorItem};
+use crate::mem::MemBuilder;
+use crate::ops::drain;
+use crate::ops::iter::Iterable;
+use crate::traits::Trait;
+use crate::{into_range, AnyVec};
+
+/// [`AnyVec`] parallel iterator.
+///
+/// Split [`Iter`] index range between threads.
+/// Return [`ElementRef`] or [`ElementMut`] items, depending on `IterItem`.
+pub struct ParIter<'a, Traits: ?Sized + Trait, M: MemBuilder, IterItem>
+where
+    IterItem: IteratorItem<'a, AnyVecPtr<Traits, M>>
+{
+    iter: Iter<'a, AnyVecPtr<Traits, M>, IterItem>
+}
+
+/// Reference [`AnyVec`] parallel iterator. Return [`ElementRef`] items.
+///
+/// This is created by `par_iter` of [`&AnyVec`] [`IntoParallelIterator`].
+///
+/// [`&AnyVec`]: AnyVec
+pub type ParIterRef<'a, Traits, M> = ParIter<'a, Traits, M, ElementRefIterItem<'a, Traits, M>>;
+
+/// Mutable reference [`AnyVec`] parallel iterator. Return [`ElementMut`] items.
+///
+/// This is created by `par_iter_mut` of [`&mut AnyVec`] [`IntoParallelIterator`].
+///
+/// [`&mut AnyVec`]: AnyVec
+pub type ParIterMut<'a, Traits, M> = ParIter<'a, Traits, M, ElementMutIterItem<'a, Traits, M>>;
+
+impl<'a, Traits, M, IterItem> ParallelIterator for ParIter<'a, Traits, M, IterItem>
+where
+    Traits: ?Sized + Trait,
+    M: MemBuilder,
+    IterItem: IteratorItem<'a, AnyVecPtr<Traits, M>>,
+    IterItem::Item: Send,
+    Self: Send
+{
+    type Item = IterItem::Item;
+
+    #[inline]
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<'a, Traits, M, IterItem> IndexedParallelIterator for ParIter<'a, Traits, M, IterItem>
+where
+    Traits: ?Sized + Trait,
+    M: MemBuilder,
+    IterItem: IteratorItem<'a, AnyVecPtr<Traits, M>>,
+    IterItem::Item: Send,
+    Self: Send
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    #[inline]
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self)
+    }
+}
+
+impl<'a, Traits, M, IterItem> Producer for ParIter<'a, Traits, M, IterItem>
+where
+    Traits: ?Sized + Trait,
+    M: MemBuilder,
+    IterItem: IteratorItem<'a, AnyVecPtr<Traits, M>>,
+    Self: Send
+{
+    type Item = IterItem::Item;
+    type IntoIter = Iter<'a, AnyVecPtr<Traits, M>, IterItem>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let Iter{any_vec_ptr, index: start, end, ..} = self.iter;
+        let mid = start + index;
+        (
+            Self{ iter: Iter::new(any_vec_ptr, start, mid) },
+            Self{ iter: Iter::new(any_vec_ptr, mid, end) }
+        )
+    }
+}
+
+// ElementRef, like &T, can be sent if T: Sync.
+unsafe impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Send
+for
+    ParIterRef<'a, Traits, M>
+where
+    AnyVec<Traits, M>: Sync
+{}
+
+// ElementMut, like &mut T, can be sent if T: Send.
+// But all parts share AnyVec memory, like &T - so Mem must be Sync.
+unsafe impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Send
+for
+    ParIterMut<'a, Traits, M>
+where
+    AnyVec<Traits, M>: Send,
+    M: Sync,
+    M::Mem: Sync
+{}
+
+impl<'a, Traits: ?Sized + Trait, M: MemBuilder + 'a> IntoParallelIterator for &'a AnyVec<Traits, M>
+where
+    AnyVec<Traits, M>: Sync
+{
+    type Iter = ParIterRef<'a, Traits, M>;
+    type Item = ElementRef<'a, Traits, M>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter{ iter: self.iter() }
+    }
+}
+
+impl<'a, Traits: ?Sized + Trait, M: MemBuilder + 'a> IntoParallelIterator for &'a mut AnyVec<Traits, M>
+where
+    AnyVec<Traits, M>: Send,
+    M: Sync,
+    M::Mem: Sync
+{
+    type Iter = ParIterMut<'a, Traits, M>;
+    type Item = ElementMut<'a, Traits, M>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        ParIter{ iter: self.iter_mut() }
+    }
+}
+
+/// A draining [`AnyVec`] parallel iterator. Return items as [`Element`]s.
+///
+/// This is created by `par_drain` of [`&mut AnyVec`] [`ParallelDrainRange`].
+///
+/// [`&mut AnyVec`]: AnyVec
+pub struct ParDrain<'a, Traits: ?Sized + Trait, M: MemBuilder>{
+    drain: drain::Drain<'a, AnyVecPtr<Traits, M>>
+}
+
+impl<'a, Traits: ?Sized + Trait, M: MemBuilder + 'a> ParallelDrainRange<usize> for &'a mut AnyVec<Traits, M>
+where
+    AnyVec<Traits, M>: Send,
+    M: Sync,
+    M::Mem: Sync
+{
+    type Iter = ParDrain<'a, Traits, M>;
+    type Item = Element<'a, Traits, M>;
+
+    #[inline]
+    fn par_drain<R: RangeBounds<usize>>(self, range: R) -> Self::Iter {
+        let Range{start, end} = into_range(self.len(), range);
+        ParDrain{ drain: drain::Drain::new(AnyVecPtr::from(self), start, end) }
+    }
+}
+
+impl<'a, Traits: ?Sized + Trait, M: MemBuilder> ParallelIterator for ParDrain<'a, Traits, M>
+where
+    AnyVec<Traits, M>: Send,
+    M: Sync,
+    M::Mem: Sync
+{
+    type Item = Element<'a, Traits, M>;
+
+    #[inline]
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.drain.iter().len())
+    }
+}
+
+impl<'a, Traits: ?Sized + Trait, M: MemBuilder> IndexedParallelIterator for ParDrain<'a, Traits, M>
+where
+    AnyVec<Traits, M>: Send,
+    M: Sync,
+    M::Mem: Sync
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.drain.iter().len()
+    }
+
+    #[inline]
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        // Elements are owned by producers from now on.
+        // Drain will only move the tail on drop.
+        let iter = self.drain.iter_mut();
+        let producer = DrainProducer{ iter: Iter::new(iter.any_vec_ptr, iter.index, iter.end) };
+        iter.index = iter.end;
+
+        callback.callback(producer)
+    }
+}
+
+/// Drop not consumed elements on destruction.
+struct DrainProducer<'a, AnyVecPtr: IAnyVecRawPtr>{
+    iter: Iter<'a, AnyVecPtr>
+}
+
+impl<'a, AnyVecPtr: IAnyVecRawPtr> Iterator for DrainProducer<'a, AnyVecPtr>{
+    type Item = ElementPointer<'a, AnyVecPtr>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, AnyVecPtr: IAnyVecRawPtr> DoubleEndedIterator for DrainProducer<'a, AnyVecPtr>{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, AnyVecPtr: IAnyVecRawPtr> ExactSizeIterator for DrainProducer<'a, AnyVecPtr>{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, Traits: ?Sized + Trait, M: MemBuilder> Producer for DrainProducer<'a, AnyVecPtr<Traits, M>>
+where
+    AnyVec<Traits, M>: Send,
+    M: Sync,
+    M::Mem: Sync
+{
+    type Item = Element<'a, Traits, M>;
+    type IntoIter = Self;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    #[inline]
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let Iter{any_vec_ptr, index: start, end, ..} = self.iter;
+        let mid = start + index;
+        self.iter.index = end;
+        (
+            Self{ iter: Iter::new(any_vec_ptr, start, mid) },
+            Self{ iter: Iter::new(any_vec_ptr, mid, end) }
+        )
+    }
+}
+
+impl<'a, AnyVecPtr: IAnyVecRawPtr> Drop for DrainProducer<'a, AnyVecPtr>{
+    #[inline]
+    fn drop(&mut self) {
+        unsafe{
+            drop_elements_range(self.iter.any_vec_ptr, self.iter.index, self.iter.end);
+        }
+    }
+}
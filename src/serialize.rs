@@ -0,0 +1,63 @@
+use serde::{Serialize, Serializer};
+use crate::{AnyVec, AnyVecMut, AnyVecRef, AnyVecTyped};
+use crate::any_value::AnyValueSizeless;
+use crate::mem::MemBuilder;
+use crate::traits::{FnTableFor, Serializable, Trait};
+
+/// Element as [`erased_serde::Serialize`].
+pub type SerializeFn = unsafe fn(ptr: *const u8) -> *const dyn erased_serde::Serialize;
+unsafe fn serialize_fn<T: Serialize + 'static>(ptr: *const u8) -> *const dyn erased_serde::Serialize{
+    ptr as *const T
+}
+
+macro_rules! impl_serializable {
+    ($($t:ty: $($bound:path),*;)*) => {$(
+        impl Trait for $t{
+            type FnTable = SerializeFn;
+        }
+        unsafe impl<T: Serialize + 'static $(+ $bound)*> FnTableFor<T> for $t{
+            const FN_TABLE: SerializeFn = serialize_fn::<T>;
+        }
+    )*}
+}
+
+impl_serializable!(
+    dyn Serializable: ;
+    dyn Serializable + Send: Send;
+    dyn Serializable + Sync: Sync;
+    dyn Serializable + Send + Sync: Send, Sync;
+);
+
+impl<Traits, M> Serialize for AnyVec<Traits, M>
+where
+    Traits: ?Sized + Serializable + Trait<FnTable = SerializeFn>,
+    M: MemBuilder
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let serialize_fn = *self.fn_table();
+        serializer.collect_seq(
+            self.iter().map(|element| unsafe{ &*(serialize_fn)(element.as_bytes_ptr()) })
+        )
+    }
+}
+
+impl<'a, T: Serialize + 'static, M: MemBuilder + 'a> Serialize for AnyVecTyped<'a, T, M>{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'a, T: Serialize + 'static, M: MemBuilder + 'a> Serialize for AnyVecRef<'a, T, M>{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'a, T: Serialize + 'static, M: MemBuilder + 'a> Serialize for AnyVecMut<'a, T, M>{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
@@ -0,0 +1,257 @@
+//! [`AnyVec`] deserialization, by stable type names.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::{cmp, fmt};
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use serde::de::{DeserializeOwned, DeserializeSeed, Error as _, SeqAccess, Visitor};
+use serde::ser::{Error as _, SerializeTuple};
+use serde::{Deserializer, Serialize, Serializer};
+use crate::{AnyVec, SatisfyTraits};
+use crate::mem::MemBuilder;
+use crate::traits::{Serializable, Trait};
+
+type DeserializeFn<Traits> =
+    fn(&mut dyn erased_serde::Deserializer<'_>) -> Result<AnyVec<Traits>, erased_serde::Error>;
+fn deserialize_fn<T, Traits>(deserializer: &mut dyn erased_serde::Deserializer<'_>)
+    -> Result<AnyVec<Traits>, erased_serde::Error>
+where
+    T: SatisfyTraits<Traits> + DeserializeOwned + 'static,
+    Traits: ?Sized + Trait
+{
+    let vec: Vec<T> = erased_serde::deserialize(deserializer)?;
+    Ok(AnyVec::from_vec(vec))
+}
+
+struct TypeEntry<Traits: ?Sized + Trait>{
+    new: fn() -> AnyVec<Traits>,
+    deserialize: DeserializeFn<Traits>,
+}
+
+/// Registry of types, that can be deserialized into [`AnyVec`].
+///
+/// Maps stable type names to constructors and deserialize functions.
+/// [`tagged`] serialize [`AnyVec`] (or slice of them) with type name,
+/// and [`seed`] deserialize it back, without naming types.
+///
+/// ```rust
+/// # use any_vec::{AnyVec, TypeRegistry};
+/// # use any_vec::traits::Serializable;
+/// # use serde::de::DeserializeSeed;
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<u32>("u32").register::<String>("string");
+///
+/// let mut ints: AnyVec<dyn Serializable> = AnyVec::new::<u32>();
+/// ints.downcast_mut::<u32>().unwrap().extend([1, 2]);
+/// let mut strings: AnyVec<dyn Serializable> = AnyVec::new::<String>();
+/// strings.downcast_mut::<String>().unwrap().push(String::from("a"));
+/// let columns = vec![ints, strings];
+///
+/// let json = serde_json::to_string(&registry.tagged(columns.as_slice())).unwrap();
+/// assert_eq!(json, r#"[["u32",[1,2]],["string",["a"]]]"#);
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(&json);
+/// let columns = registry.seed::<Vec<AnyVec<dyn Serializable>>>()
+///     .deserialize(&mut deserializer).unwrap();
+/// assert_eq!(columns[1].downcast_ref::<String>().unwrap().as_slice(), &["a"]);
+/// ```
+///
+/// [`tagged`]: Self::tagged
+/// [`seed`]: Self::seed
+pub struct TypeRegistry<Traits: ?Sized + Trait = dyn Serializable>{
+    by_name: BTreeMap<&'static str, TypeEntry<Traits>>,
+    by_type: BTreeMap<TypeId, &'static str>,
+}
+
+impl<Traits: ?Sized + Trait> Default for TypeRegistry<Traits>{
+    #[inline]
+    fn default() -> Self {
+        Self{
+            by_name: BTreeMap::new(),
+            by_type: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Traits: ?Sized + Trait> TypeRegistry<Traits>{
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `T` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `T` or `name` already registered.
+    pub fn register<T>(&mut self, name: &'static str) -> &mut Self
+    where
+        T: SatisfyTraits<Traits> + DeserializeOwned + 'static
+    {
+        assert!(!self.by_name.contains_key(name), "Type name already registered!");
+        assert!(!self.by_type.contains_key(&TypeId::of::<T>()), "Type already registered!");
+        self.by_name.insert(name, TypeEntry{
+            new: AnyVec::new::<T>,
+            deserialize: deserialize_fn::<T, Traits>,
+        });
+        self.by_type.insert(TypeId::of::<T>(), name);
+        self
+    }
+
+    /// Registered name of type.
+    #[inline]
+    pub fn name_of(&self, type_id: TypeId) -> Option<&'static str> {
+        self.by_type.get(&type_id).copied()
+    }
+
+    /// Empty [`AnyVec`] of type, registered under `name`.
+    #[inline]
+    pub fn new_any_vec(&self, name: &str) -> Option<AnyVec<Traits>> {
+        self.by_name.get(name).map(|entry| (entry.new)())
+    }
+
+    /// [`Serialize`] `value` with type name.
+    ///
+    /// Implemented for [`AnyVec`] - as `(name, elements)` tuple, and
+    /// for `[AnyVec]` - as sequence of them.
+    #[inline]
+    pub fn tagged<'a, T: ?Sized>(&'a self, value: &'a T) -> Tagged<'a, T, Traits> {
+        Tagged{ registry: self, value }
+    }
+
+    /// [`DeserializeSeed`] for [`tagged`] [`AnyVec`], or `Vec` of them.
+    ///
+    /// [`tagged`]: Self::tagged
+    #[inline]
+    pub fn seed<T>(&self) -> Seed<'_, T, Traits> {
+        Seed{ registry: self, phantom: PhantomData }
+    }
+}
+
+/// Type name tagged [`Serialize`]. Constructed with [`TypeRegistry::tagged`].
+pub struct Tagged<'a, T: ?Sized, Traits: ?Sized + Trait>{
+    registry: &'a TypeRegistry<Traits>,
+    value: &'a T,
+}
+
+impl<'a, Traits, M> Serialize for Tagged<'a, AnyVec<Traits, M>, Traits>
+where
+    Traits: ?Sized + Trait,
+    M: MemBuilder,
+    AnyVec<Traits, M>: Serialize
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = self.registry.name_of(self.value.element_typeid())
+            .ok_or_else(|| S::Error::custom(format_args!(
+                "type {} is not registered", self.value.element_type_name()
+            )))?;
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(name)?;
+        tuple.serialize_element(self.value)?;
+        tuple.end()
+    }
+}
+
+impl<'a, Traits, M> Serialize for Tagged<'a, [AnyVec<Traits, M>], Traits>
+where
+    Traits: ?Sized + Trait,
+    M: MemBuilder,
+    AnyVec<Traits, M>: Serialize
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.value.iter().map(|any_vec| self.registry.tagged(any_vec)))
+    }
+}
+
+/// Type name tagged [`DeserializeSeed`]. Constructed with [`TypeRegistry::seed`].
+pub struct Seed<'a, T, Traits: ?Sized + Trait>{
+    registry: &'a TypeRegistry<Traits>,
+    phantom: PhantomData<fn() -> T>
+}
+
+impl<'a, T, Traits: ?Sized + Trait> Clone for Seed<'a, T, Traits>{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T, Traits: ?Sized + Trait> Copy for Seed<'a, T, Traits>{}
+
+impl<'a, 'de, Traits: ?Sized + Trait> DeserializeSeed<'de> for Seed<'a, AnyVec<Traits>, Traits>{
+    type Value = AnyVec<Traits>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de, Traits: ?Sized + Trait> Visitor<'de> for Seed<'a, AnyVec<Traits>, Traits>{
+    type Value = AnyVec<Traits>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("(type name, elements) tuple")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let name: String = seq.next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let entry = self.registry.by_name.get(name.as_str())
+            .ok_or_else(|| A::Error::custom(format_args!("type {} is not registered", name)))?;
+        seq.next_element_seed(Elements(entry.deserialize))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+impl<'a, 'de, Traits: ?Sized + Trait> DeserializeSeed<'de> for Seed<'a, Vec<AnyVec<Traits>>, Traits>{
+    type Value = Vec<AnyVec<Traits>>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de, Traits: ?Sized + Trait> Visitor<'de> for Seed<'a, Vec<AnyVec<Traits>>, Traits>{
+    type Value = Vec<AnyVec<Traits>>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("sequence of (type name, elements) tuples")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Size hint comes from input - do not trust it, like serde does.
+        let mut any_vecs = Vec::with_capacity(cmp::min(seq.size_hint().unwrap_or(0), 4096));
+        while let Some(any_vec) = seq.next_element_seed(self.registry.seed::<AnyVec<Traits>>())? {
+            any_vecs.push(any_vec);
+        }
+        Ok(any_vecs)
+    }
+}
+
+/// Elements of registered type.
+struct Elements<Traits: ?Sized + Trait>(DeserializeFn<Traits>);
+
+impl<'de, Traits: ?Sized + Trait> DeserializeSeed<'de> for Elements<Traits>{
+    type Value = AnyVec<Traits>;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let mut deserializer = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.0)(&mut deserializer).map_err(D::Error::custom)
+    }
+}
+
+impl<Traits: ?Sized + Trait> fmt::Debug for TypeRegistry<Traits>{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypeRegistry")
+            .field("types", &self.by_name.keys())
+            .finish()
+    }
+}
@@ -0,0 +1,84 @@
+use itertools::assert_equal;
+use any_vec::{AnySlice, AnySliceMut, AnyVec};
+use any_vec::any_value::AnyValue;
+use any_vec::mem::Stack;
+use any_vec::traits::*;
+
+fn strings(slice: AnySlice) -> Vec<String> {
+    slice.iter().map(|e| e.downcast_ref::<String>().unwrap().clone()).collect()
+}
+
+#[test]
+fn any_slice_test(){
+    let mut any_vec: AnyVec<dyn None, Stack<512>> = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap()
+        .extend((0..5).map(|i| i.to_string()));
+
+    let slice = any_vec.slice(1..4);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.element_typeid(), any_vec.element_typeid());
+    assert_eq!(slice.at(0).downcast_ref::<String>().unwrap(), "1");
+    assert!(slice.get(3).is_none());
+    assert!(slice.downcast_ref::<usize>().is_none());
+    assert!(slice.try_downcast_ref::<usize>().is_err());
+    assert_eq!(slice.downcast_ref::<String>().unwrap(), &["1", "2", "3"]);
+    assert_eq!(slice.as_bytes().len(), 3 * size_of::<String>());
+    assert_equal(slice.iter().rev().map(|e| e.downcast_ref::<String>().unwrap().as_str()), ["3", "2", "1"]);
+
+    let (left, right) = slice.split_at(1);
+    assert_eq!(strings(left), ["1"]);
+    assert_eq!(strings(right), ["2", "3"]);
+    assert!(any_vec.slice(5..).is_empty());
+
+    // From chunk and typed slice.
+    assert_eq!(strings(any_vec.chunks(2).nth(1).unwrap()), ["2", "3"]);
+    let typed = [1u32, 2, 3];
+    assert_eq!(AnySlice::from(&typed[..]).downcast_ref::<u32>().unwrap(), &typed);
+}
+
+#[test]
+fn any_slice_mut_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap()
+        .extend((0..5).map(|i| i.to_string()));
+
+    let mut slice = any_vec.slice_mut(..);
+    slice.swap(0, 4);
+    slice.swap(2, 2);
+    {
+        let (mut left, mut right) = slice.split_at_mut(2);
+        left.at_mut(0).downcast_mut::<String>().unwrap().push('a');
+        for mut e in right.iter_mut(){
+            e.downcast_mut::<String>().unwrap().push('b');
+        }
+    }
+    slice.downcast_mut::<String>().unwrap()[1].push('c');
+    assert_eq!(strings(slice.as_slice()), ["4a", "1c", "2b", "3b", "0b"]);
+    assert_eq!(strings(slice.into()), ["4a", "1c", "2b", "3b", "0b"]);
+
+    let mut typed = [1u32, 2, 3];
+    let mut slice = AnySliceMut::from(&mut typed[..]);
+    slice.swap(0, 2);
+    *slice.at_mut(1).downcast_mut::<u32>().unwrap() = 20;
+    assert_eq!(typed, [3, 20, 1]);
+}
+
+#[test]
+fn any_slice_element_test(){
+    let mut any_vec: AnyVec<dyn Debuggable> = AnyVec::new::<u32>();
+    any_vec.downcast_mut::<u32>().unwrap().extend([1, 2]);
+    let slice = any_vec.slice(..);
+    assert_eq!(format!("{slice:?}"), "[1, 2]");
+    assert_eq!(slice.at(1).value_typeid(), std::any::TypeId::of::<u32>());
+
+    let any_vec: AnyVec = AnyVec::new::<u32>();
+    assert!(format!("{:?}", any_vec.slice(..)).starts_with("AnySlice"));
+}
+
+#[test]
+#[should_panic]
+fn any_slice_swap_out_of_bounds_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<u32>();
+    any_vec.downcast_mut::<u32>().unwrap().push(1);
+    any_vec.slice_mut(..).swap(0, 1);
+}
@@ -1,7 +1,8 @@
 use std::any::TypeId;
 use std::mem::size_of;
 use std::ptr::NonNull;
-use any_vec::any_value::{AnyValue, AnyValueMut, AnyValueRaw, AnyValueWrapper};
+use any_vec::any_value::{AnyValue, AnyValueMut, AnyValueRaw, AnyValueTypeless, AnyValueWrapper};
+use any_vec::traits::None;
 
 #[test]
 fn swap_test(){
@@ -57,4 +58,122 @@ fn swap_test(){
         assert_eq!(a1.downcast_ref::<String>().unwrap(), &String::from("1"));
         assert_eq!(a2.downcast_ref::<String>().unwrap(), &String::from("2"));
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn any_value_box_test(){
+    use std::any::Any;
+    use any_vec::AnyVec;
+    use any_vec::any_value::{AnyValueBox, AnyValueCloneable};
+    use any_vec::traits::{Cloneable, Debuggable};
+
+    // from AnyVec
+    let mut any_vec: AnyVec<dyn Cloneable> = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap().extend([String::from("0"), String::from("1")]);
+    let mut b1 = any_vec.remove(0).into_box();
+    let b2 = any_vec.pop().unwrap().into_box();
+    assert!(any_vec.is_empty());
+    b1.downcast_mut::<String>().unwrap().push('!');
+
+    // clone
+    let b3 = b1.clone();
+    any_vec.push(b1.lazy_clone());
+    any_vec.push(b1);
+    any_vec.push(b2);
+    any_vec.push(b3);
+    assert_eq!(any_vec.downcast_ref::<String>().unwrap().as_slice(), &["0!", "0!", "1", "0!"]);
+
+    // Box<T>
+    let b: AnyValueBox<dyn Debuggable> = Box::new(String::from("2")).into();
+    assert_eq!(format!("{:?}", b), "\"2\"");
+    let b = b.downcast_box::<usize>().unwrap_err();
+    assert_eq!(*b.downcast_box::<String>().unwrap(), "2");
+
+    // Box<dyn Any>
+    let b: AnyValueBox = (Box::new(String::from("3")) as Box<dyn Any>).into();
+    assert_eq!(b.value_typeid(), TypeId::of::<String>());
+    assert_eq!(b.size(), size_of::<String>());
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.push(b);
+    let any = any_vec.pop().unwrap().into_box().into_any().ok().unwrap();
+    assert_eq!(any.downcast_ref::<String>().unwrap(), "3");
+
+    // type name
+    let b: AnyValueBox = AnyValueBox::new(String::from("4"));
+    assert_eq!(
+        b.try_downcast_ref::<usize>().unwrap_err().to_string(),
+        "can not downcast alloc::string::String to usize"
+    );
+    let b = AnyValueBox::from_any(b.into_any().ok().unwrap(), "alloc::string::String");
+    assert_eq!(b.try_downcast::<usize>().unwrap_err().actual, "alloc::string::String");
+    let b: AnyValueBox = (Box::new(1u8) as Box<dyn Any>).into();
+    assert_eq!(b.value_type_name(), "dyn core::any::Any");
+
+    // ZST
+    let b = AnyValueBox::<dyn None>::new(());
+    assert_eq!(b.downcast::<()>(), Some(()));
+}
+
+#[test]
+fn any_value_inline_test(){
+    use std::rc::Rc;
+    use any_vec::{AnyVec, ElementVTable};
+    use any_vec::any_value::{AnyValueCloneable, AnyValueInline};
+    use any_vec::mem::Stack;
+    use any_vec::traits::Cloneable;
+
+    let rc = Rc::new(0);
+    let mut any_vec: AnyVec<dyn None, Stack<64>> = AnyVec::new::<Rc<i32>>();
+    any_vec.downcast_mut::<Rc<i32>>().unwrap().extend([rc.clone(), rc.clone()]);
+
+    let mut value: AnyValueInline<16> = any_vec.pop().unwrap().into_inline().ok().unwrap();
+    assert_eq!(value.value_typeid(), TypeId::of::<Rc<i32>>());
+    assert_eq!(value.size(), size_of::<Rc<i32>>());
+    assert!(Rc::ptr_eq(value.downcast_mut::<Rc<i32>>().unwrap(), &rc));
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    // drop
+    let dropped: AnyValueInline<16> = any_vec.pop().unwrap().into_inline().ok().unwrap();
+    drop(dropped);
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    // push back
+    any_vec.push(value);
+    assert_eq!(any_vec.len(), 1);
+    assert_eq!(Rc::strong_count(&rc), 2);
+
+    // does not fit
+    let mut any_vec: AnyVec = AnyVec::new::<[u8; 32]>();
+    any_vec.downcast_mut::<[u8; 32]>().unwrap().push([1; 32]);
+    let value = any_vec.pop().unwrap().into_inline::<16>().err().unwrap();
+    assert_eq!(value.downcast::<[u8; 32]>().unwrap(), [1; 32]);
+
+    #[repr(align(32))]
+    struct Aligned;
+    assert!(AnyValueInline::<16>::try_new(Aligned).is_err());
+
+    let value = AnyValueInline::<32>::new(String::from("Hello"));
+    assert_eq!(value.value_type_name(), "alloc::string::String");
+    assert_eq!(value.downcast::<String>().unwrap(), "Hello");
+
+    // from any value
+    let vtable = ElementVTable::of::<String, dyn None>();
+    let value = AnyValueWrapper::new(String::from("Hello"));
+    let value = unsafe{ AnyValueInline::<32>::try_from_any_value(value, vtable) }.ok().unwrap();
+    assert_eq!(value.try_downcast_ref::<usize>().unwrap_err().to_string(),
+        "can not downcast alloc::string::String to usize");
+    let value = AnyValueWrapper::new(String::from("Hello"));
+    let value = unsafe{ AnyValueInline::<8>::try_from_any_value(value, vtable) }.err().unwrap();
+    assert_eq!(value.downcast::<String>().unwrap(), "Hello");
+
+    // cloneable
+    let mut any_vec: AnyVec<dyn Cloneable> = AnyVec::new::<Rc<i32>>();
+    any_vec.downcast_mut::<Rc<i32>>().unwrap().push(rc.clone());
+    let value: AnyValueInline<16, dyn Cloneable> = any_vec.pop().unwrap().into_inline().ok().unwrap();
+    let cloned = value.clone();
+    assert_eq!(Rc::strong_count(&rc), 4);
+    any_vec.push(value.lazy_clone());
+    any_vec.push(cloned);
+    assert_eq!(any_vec.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 5);
+}
@@ -442,4 +442,694 @@ fn any_vec_debug() {
     let any_vec: AnyVec = AnyVec::new::<usize>();
     let typeid = TypeId::of::<usize>();
     assert_eq!(format!("{any_vec:?}"), format!("AnyVec {{ typeid: {typeid:?}, len: 0 }}"));
-}
\ No newline at end of file
+}
+
+#[test]
+fn retain_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    for i in 0..10 {
+        any_vec.push(AnyValueWrapper::new(i.to_string()));
+    }
+
+    any_vec.retain(|e| e.downcast_ref::<String>().unwrap().parse::<usize>().unwrap() % 3 != 0);
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("1"),
+        String::from("2"),
+        String::from("4"),
+        String::from("5"),
+        String::from("7"),
+        String::from("8"),
+    ]);
+}
+
+#[test]
+fn retain_mut_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    for i in 0..5 {
+        any_vec.push(AnyValueWrapper::new(i.to_string()));
+    }
+
+    any_vec.retain_mut(|mut e| {
+        let s = e.downcast_mut::<String>().unwrap();
+        s.push('+');
+        s != "2+"
+    });
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("0+"),
+        String::from("1+"),
+        String::from("3+"),
+        String::from("4+"),
+    ]);
+}
+
+#[test]
+fn retain_panic_test(){
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    for i in 0..6 {
+        any_vec.push(AnyValueWrapper::new(i.to_string()));
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        any_vec.retain(|e| {
+            let s = e.downcast_ref::<String>().unwrap();
+            if s == "4" {
+                panic!();
+            }
+            s != "1"
+        });
+    }));
+    assert!(result.is_err());
+
+    // Processed elements filtered, unprocessed kept.
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("0"),
+        String::from("2"),
+        String::from("3"),
+        String::from("4"),
+        String::from("5"),
+    ]);
+}
+
+#[test]
+fn truncate_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    for i in 0..5 {
+        any_vec.push(AnyValueWrapper::new(i.to_string()));
+    }
+
+    any_vec.truncate(10);
+    assert_eq!(any_vec.len(), 5);
+
+    any_vec.truncate(2);
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("0"),
+        String::from("1"),
+    ]);
+
+    any_vec.truncate(0);
+    assert!(any_vec.is_empty());
+}
+
+#[test]
+fn split_off_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    for i in 0..5 {
+        any_vec.push(AnyValueWrapper::new(i.to_string()));
+    }
+
+    let tail = any_vec.split_off(2);
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("0"),
+        String::from("1"),
+    ]);
+    assert_equal(tail.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("2"),
+        String::from("3"),
+        String::from("4"),
+    ]);
+
+    let empty = any_vec.split_off(2);
+    assert!(empty.is_empty());
+    assert_eq!(empty.element_typeid(), TypeId::of::<String>());
+
+    let all = any_vec.split_off(0);
+    assert!(any_vec.is_empty());
+    assert_eq!(all.len(), 2);
+}
+
+#[test]
+fn split_off_stack_test(){
+    let mut any_vec: AnyVec<dyn any_vec::traits::None, Stack<256>> = AnyVec::new::<usize>();
+    any_vec.downcast_mut::<usize>().unwrap().extend(0..10);
+
+    let tail = any_vec.split_off(7);
+    assert_equal(any_vec.downcast_ref::<usize>().unwrap().as_slice().iter().copied(), 0..7);
+    assert_equal(tail.downcast_ref::<usize>().unwrap().as_slice().iter().copied(), 7..10);
+}
+
+#[test]
+fn resize_with_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.push(AnyValueWrapper::new(String::from("0")));
+
+    let mut i = 0;
+    any_vec.resize_with(4, || {
+        i += 1;
+        AnyValueWrapper::new(i.to_string())
+    });
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("0"),
+        String::from("1"),
+        String::from("2"),
+        String::from("3"),
+    ]);
+
+    any_vec.resize_with(1, || -> AnyValueWrapper<String> { unreachable!() });
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().as_slice(), &[
+        String::from("0"),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn resize_with_type_mismatch_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.resize_with(1, || AnyValueWrapper::new(0usize));
+}
+
+#[test]
+fn sort_by_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap().extend(
+        ["bb", "a", "ccc", "dd", "e"].map(String::from)
+    );
+
+    // stable
+    any_vec.sort_by_key(|e| e.downcast_ref::<String>().unwrap().len());
+    assert_equal(
+        any_vec.downcast_ref::<String>().unwrap().as_slice(),
+        &["a", "e", "bb", "dd", "ccc"].map(String::from)
+    );
+
+    any_vec.sort_unstable_by(|l, r|
+        r.downcast_ref::<String>().unwrap().cmp(l.downcast_ref::<String>().unwrap())
+    );
+    assert_equal(
+        any_vec.downcast_ref::<String>().unwrap().as_slice(),
+        &["e", "dd", "ccc", "bb", "a"].map(String::from)
+    );
+}
+
+#[test]
+fn sort_by_panic_test(){
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap().extend((0..50).rev().map(|i| i.to_string()));
+
+    let mut count = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        any_vec.sort_by(|l, r| {
+            count += 1;
+            if count == 100 { panic!() }
+            l.downcast_ref::<String>().unwrap().cmp(r.downcast_ref::<String>().unwrap())
+        });
+    }));
+    assert!(result.is_err());
+
+    // All elements still in place.
+    let mut vec: Vec<String> = any_vec.downcast_ref::<String>().unwrap().as_slice().to_vec();
+    vec.sort_by_key(|s| s.parse::<usize>().unwrap());
+    assert_equal(vec, (0..50).map(|i| i.to_string()));
+}
+
+#[test]
+fn type_name_test(){
+    use any_vec::any_value::{AnyValue, DowncastError};
+
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    assert_eq!(any_vec.element_type_name(), "alloc::string::String");
+    assert!(any_vec.try_downcast_ref::<String>().is_ok());
+
+    let err = any_vec.try_downcast_mut::<usize>().err().unwrap();
+    assert_eq!(err, DowncastError{ expected: "usize", actual: "alloc::string::String" });
+    assert_eq!(err.to_string(), "can not downcast alloc::string::String to usize");
+
+    any_vec.push(AnyValueWrapper::new(String::from("0")));
+    {
+        let mut e = any_vec.at_mut(0);
+        assert_eq!(e.value_type_name(), "alloc::string::String");
+        assert_eq!(e.try_downcast_ref::<String>().unwrap(), "0");
+        assert_eq!(e.try_downcast_mut::<u8>().unwrap_err().expected, "u8");
+    }
+
+    let e = any_vec.pop().unwrap();
+    assert_eq!(e.value_type_name(), "alloc::string::String");
+    assert_eq!(e.try_downcast::<usize>().unwrap_err().actual, "alloc::string::String");
+
+    let v = AnyValueWrapper::new(1usize);
+    assert_eq!(v.value_type_name(), "usize");
+    assert_eq!(v.try_downcast::<usize>(), Ok(1));
+
+    // Type erased
+    let mut s = String::from("1");
+    let raw = unsafe{ AnyValueRaw::from_vtable(
+        NonNull::from(&mut s).cast::<u8>(),
+        any_vec.vtable()
+    ) };
+    assert_eq!(raw.value_type_name(), "alloc::string::String");
+    assert_eq!(
+        raw.try_downcast_ref::<usize>().unwrap_err().to_string(),
+        "can not downcast alloc::string::String to usize"
+    );
+    let raw = unsafe{ AnyValueRaw::new(
+        NonNull::from(&mut s).cast::<u8>(),
+        size_of::<String>(),
+        TypeId::of::<String>()
+    ) };
+    assert_eq!(raw.try_downcast_ref::<usize>().unwrap_err().actual, "any_vec::any_value::Unknown");
+}
+
+#[test]
+fn try_push_test(){
+    use any_vec::any_value::AnyValue;
+    use any_vec::mem::TryReserveError;
+
+    let mut any_vec: AnyVec<dyn any_vec::traits::None, Stack<{size_of::<String>() * 2}>> = AnyVec::new::<String>();
+    assert!(any_vec.try_push(AnyValueWrapper::new(String::from("0"))).is_ok());
+    assert!(any_vec.try_insert(0, AnyValueWrapper::new(String::from("1"))).is_ok());
+    assert_eq!(any_vec.try_reserve(1), Err(TryReserveError::CapacityExceeded));
+
+    let err = any_vec.try_push(AnyValueWrapper::new(String::from("2"))).unwrap_err();
+    assert_eq!(err.error, TryReserveError::CapacityExceeded);
+    assert_eq!(err.into_value().downcast::<String>().unwrap(), "2");
+
+    let err = any_vec.try_extend([String::from("3")].map(AnyValueWrapper::new)).unwrap_err();
+    assert_eq!(err.into_value().downcast::<String>().unwrap(), "3");
+
+    assert_equal(
+        any_vec.downcast_ref::<String>().unwrap().as_slice(),
+        &["1", "0"].map(String::from)
+    );
+}
+
+#[test]
+fn try_reserve_test(){
+    use any_vec::mem::TryReserveError;
+
+    let mut any_vec: AnyVec = AnyVec::new::<u64>();
+    assert_eq!(any_vec.try_reserve(usize::MAX / 4), Err(TryReserveError::CapacityExceeded));
+    assert_eq!(any_vec.try_reserve_exact(usize::MAX), Err(TryReserveError::CapacityExceeded));
+    assert_eq!(any_vec.capacity(), 0);
+
+    assert!(any_vec.try_reserve_exact(10).is_ok());
+    assert_eq!(any_vec.capacity(), 10);
+
+    assert!(any_vec.try_extend((0..20u64).map(AnyValueWrapper::new)).is_ok());
+    assert_equal(any_vec.downcast_ref::<u64>().unwrap().as_slice().iter().copied(), 0..20);
+}
+
+#[cfg(feature="allocator_api2")]
+#[test]
+fn allocator_test(){
+    use any_vec::mem::Allocator;
+    use any_vec::traits::Cloneable;
+
+    let bump = bumpalo::Bump::new();
+    let mut any_vec: AnyVec<dyn Cloneable, Allocator<&bumpalo::Bump>> =
+        AnyVec::with_capacity_in::<String>(2, Allocator(&bump));
+    {
+        let mut vec = any_vec.downcast_mut::<String>().unwrap();
+        vec.extend((0..100).map(|i| i.to_string()));
+    }
+    assert!(any_vec.try_reserve_exact(10).is_ok());
+    any_vec.shrink_to_fit();
+    assert_eq!(any_vec.capacity(), 100);
+
+    let cloned = any_vec.clone();
+    assert_equal(
+        cloned.downcast_ref::<String>().unwrap().as_slice(),
+        &(0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+
+    let raw_parts = any_vec.into_raw_parts();
+    let any_vec: AnyVec<dyn Cloneable, Allocator<&bumpalo::Bump>> = unsafe{ AnyVec::from_raw_parts(raw_parts) };
+    assert_eq!(any_vec.len(), 100);
+}
+
+#[test]
+fn small_heap_test(){
+    use any_vec::mem::SmallHeap;
+    use any_vec::traits::None;
+
+    let mut any_vec: AnyVec<dyn None, SmallHeap<{size_of::<String>() * 4}>> = AnyVec::new::<String>();
+    assert_eq!(any_vec.capacity(), 4);
+    {
+        let mut vec = any_vec.downcast_mut::<String>().unwrap();
+        vec.extend((0..4).map(|i| i.to_string()));
+    }
+    assert_eq!(any_vec.capacity(), 4);
+
+    // spill
+    any_vec.push(AnyValueWrapper::new(String::from("4")));
+    assert!(any_vec.capacity() > 4);
+    assert_equal(
+        any_vec.downcast_ref::<String>().unwrap().as_slice(),
+        &(0..5).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+
+    // back inline
+    any_vec.pop();
+    any_vec.shrink_to_fit();
+    assert_eq!(any_vec.capacity(), 4);
+    assert_equal(
+        any_vec.downcast_ref::<String>().unwrap().as_slice(),
+        &(0..4).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+
+    // over-aligned
+    #[repr(align(64))]
+    struct Aligned(u8);
+    let mut any_vec: AnyVec<dyn None, SmallHeap<64>> = AnyVec::new::<Aligned>();
+    // empty
+    assert_eq!(any_vec.capacity(), 0);
+    assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+    assert!(any_vec.downcast_ref::<Aligned>().unwrap().as_slice().is_empty());
+
+    any_vec.downcast_mut::<Aligned>().unwrap().push(Aligned(1));
+    assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+    assert_eq!(any_vec.downcast_ref::<Aligned>().unwrap().as_slice()[0].0, 1);
+
+    // back to empty
+    any_vec.clear();
+    any_vec.shrink_to_fit();
+    assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+    assert!(any_vec.downcast_ref::<Aligned>().unwrap().as_slice().is_empty());
+}
+
+#[test]
+fn arena_test(){
+    use any_vec::mem::Arena;
+    use any_vec::traits::None;
+
+    let mut arena = Arena::new(1024);
+    for _ in 0..3 {
+        let mut v1: AnyVec<dyn None, Arena> = AnyVec::new_in::<String>(arena.clone());
+        v1.downcast_mut::<String>().unwrap().push(String::from("0"));
+        let mut v2: AnyVec<dyn None, Arena> = AnyVec::with_capacity_in::<u32>(1, arena.clone());
+        v2.downcast_mut::<u32>().unwrap().push(0);
+
+        // v2 is most recent allocation - grows in place.
+        let ptr = v2.as_bytes().as_ptr();
+        v2.downcast_mut::<u32>().unwrap().extend(1..100);
+        assert_eq!(ptr, v2.as_bytes().as_ptr());
+
+        // v1 is not - reallocates.
+        v1.downcast_mut::<String>().unwrap().extend((1..100).map(|i| i.to_string()));
+        // Bigger than chunk.
+        v2.reserve(1000);
+
+        assert_equal(
+            v1.downcast_ref::<String>().unwrap().as_slice(),
+            &(0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+        assert_equal(v2.downcast_ref::<u32>().unwrap().as_slice().iter().copied(), 0..100);
+
+        drop(v1);
+        drop(v2);
+        arena.reset();
+    }
+}
+
+#[test]
+#[should_panic(expected = "Arena is in use!")]
+fn arena_reset_in_use_test(){
+    use any_vec::mem::Arena;
+    use any_vec::traits::None;
+
+    let mut arena = Arena::new(1024);
+    let _v: AnyVec<dyn None, Arena> = AnyVec::new_in::<u32>(arena.clone());
+    arena.reset();
+}
+
+#[cfg(feature="mmap")]
+#[test]
+fn mmap_file_test(){
+    use std::io::ErrorKind;
+    use any_vec::mem::MmapFile;
+    use any_vec::traits::Cloneable;
+
+    let path = std::env::temp_dir().join(format!("any_vec_mmap_test_{}", std::process::id()));
+    {
+        let mut any_vec: AnyVec<dyn Cloneable, MmapFile> = AnyVec::new_in::<u64>(MmapFile::create(&path).unwrap());
+        any_vec.downcast_mut::<u64>().unwrap().extend(0..1000);
+
+        // Clone is anonymous.
+        let cloned = any_vec.clone();
+        assert_equal(cloned.downcast_ref::<u64>().unwrap().as_slice().iter().copied(), 0..1000);
+
+        MmapFile::persist(&mut any_vec).unwrap();
+        assert_eq!(any_vec.capacity(), 1000);
+    }
+
+    {
+        let mut any_vec = unsafe{ MmapFile::open::<u64, dyn Cloneable>(&path) }.unwrap();
+        assert_equal(any_vec.downcast_ref::<u64>().unwrap().as_slice().iter().copied(), 0..1000);
+        any_vec.downcast_mut::<u64>().unwrap().push(1000);
+        MmapFile::persist(&mut any_vec).unwrap();
+    }
+
+    let any_vec = unsafe{ MmapFile::open::<u64, dyn Cloneable>(&path) }.unwrap();
+    assert_equal(any_vec.downcast_ref::<u64>().unwrap().as_slice().iter().copied(), 0..1001);
+    drop(any_vec);
+
+    assert_eq!(unsafe{ MmapFile::open::<u32, dyn Cloneable>(&path) }.err().unwrap().kind(), ErrorKind::InvalidData);
+    assert_eq!(unsafe{ MmapFile::open::<i64, dyn Cloneable>(&path) }.err().unwrap().kind(), ErrorKind::InvalidData);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn growth_policy_test(){
+    use any_vec::mem::{Exact, Factor, HeapWith, MinCapacity, PageRounded};
+    use any_vec::traits::None;
+
+    fn push_capacities<M: any_vec::mem::MemBuilder>(mut any_vec: AnyVec<dyn None, M>) -> Vec<usize> {
+        let mut capacities = Vec::new();
+        for i in 0..10u64 {
+            any_vec.push(AnyValueWrapper::new(i));
+            if capacities.last() != Some(&any_vec.capacity()) {
+                capacities.push(any_vec.capacity());
+            }
+        }
+        capacities
+    }
+
+    assert_eq!(push_capacities(AnyVec::<dyn None>::new::<u64>()), [1, 2, 4, 8, 16]);
+    assert_eq!(push_capacities(AnyVec::new_in::<u64>(HeapWith(Exact))), (1..=10).collect::<Vec<_>>());
+    assert_eq!(push_capacities(AnyVec::new_in::<u64>(HeapWith(Factor(1.5)))), [1, 2, 3, 4, 6, 9, 13]);
+    assert_eq!(
+        push_capacities(AnyVec::new_in::<u64>(HeapWith(MinCapacity{min: 4, policy: Exact}))),
+        [4, 5, 6, 7, 8, 9, 10]
+    );
+    let page_rounded: HeapWith<PageRounded<Exact>> = HeapWith(PageRounded(Exact));
+    assert_eq!(push_capacities(AnyVec::new_in::<u64>(page_rounded)), [512]);
+
+    // reserve_exact is not affected
+    let mut any_vec: AnyVec<dyn None, HeapWith<PageRounded>> = AnyVec::new_in::<u64>(HeapWith::default());
+    any_vec.reserve_exact(3);
+    assert_eq!(any_vec.capacity(), 3);
+
+    // Policy survives raw parts.
+    let any_vec: AnyVec<dyn None, HeapWith<MinCapacity<Exact>>> =
+        AnyVec::new_in::<u64>(HeapWith(MinCapacity{min: 4, policy: Exact}));
+    let mut raw_parts = any_vec.into_raw_parts();
+    raw_parts.mem_builder = HeapWith(MinCapacity{min: 0, policy: Exact});
+    let any_vec: AnyVec<dyn None, HeapWith<MinCapacity<Exact>>> = unsafe{ AnyVec::from_raw_parts(raw_parts) };
+    assert_eq!(push_capacities(any_vec), [4, 5, 6, 7, 8, 9, 10]);
+}
+
+#[test]
+fn aligned_heap_test(){
+    use any_vec::mem::AlignedHeap;
+    use any_vec::traits::Cloneable;
+
+    type AlignedAnyVec = AnyVec<dyn Cloneable, AlignedHeap<64>>;
+    let mut any_vec: AlignedAnyVec = AnyVec::new::<u8>();
+    for i in 0..200u8 {
+        any_vec.push(AnyValueWrapper::new(i));
+        assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+    }
+    any_vec.truncate(3);
+    any_vec.shrink_to_fit();
+    assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+
+    let cloned = any_vec.clone();
+    assert_eq!(cloned.as_bytes().as_ptr() as usize % 64, 0);
+
+    let mut any_vec: AlignedAnyVec = unsafe{ AnyVec::from_raw_parts(any_vec.into_raw_parts()) };
+    any_vec.reserve_exact(1000);
+    assert_eq!(any_vec.as_bytes().as_ptr() as usize % 64, 0);
+    assert_equal(any_vec.downcast_ref::<u8>().unwrap().as_slice().iter().copied(), 0..3);
+
+    // Policy survives raw parts.
+    use any_vec::mem::Exact;
+    let any_vec: AnyVec<dyn Cloneable, AlignedHeap<64, Exact>> = AnyVec::new_in::<u8>(AlignedHeap(Exact));
+    let mut any_vec: AnyVec<dyn Cloneable, AlignedHeap<64, Exact>> = unsafe{ AnyVec::from_raw_parts(any_vec.into_raw_parts()) };
+    for i in 0..3u8 {
+        any_vec.push(AnyValueWrapper::new(i));
+        assert_eq!(any_vec.capacity(), i as usize + 1);
+    }
+}
+
+#[test]
+fn vec_conversion_test(){
+    use any_vec::traits::Cloneable;
+
+    let mut vec = Vec::with_capacity(10);
+    vec.extend([String::from("0"), String::from("1"), String::from("2")]);
+    let ptr = vec.as_ptr();
+
+    let mut any_vec: AnyVec<dyn Cloneable> = AnyVec::from_vec(vec);
+    assert_eq!(any_vec.len(), 3);
+    assert_eq!(any_vec.capacity(), 10);
+    any_vec.downcast_mut::<String>().unwrap().push(String::from("3"));
+    assert_eq!(any_vec.downcast_ref::<String>().unwrap().as_slice().as_ptr(), ptr);
+
+    let copy = any_vec.downcast_mut::<String>().unwrap().to_vec();
+    assert_equal(copy.iter().map(String::as_str), ["0", "1", "2", "3"]);
+
+    let any_vec = any_vec.into_vec::<usize>().unwrap_err();
+    let vec = any_vec.into_vec::<String>().unwrap();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec.capacity(), 10);
+    assert_eq!(vec, copy);
+
+    // ZST
+    let any_vec: AnyVec = AnyVec::from_vec(vec![(); 5]);
+    assert_eq!(any_vec.len(), 5);
+    assert_eq!(any_vec.into_vec::<()>().unwrap().len(), 5);
+}
+
+#[test]
+fn chunks_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<u32>();
+    any_vec.downcast_mut::<u32>().unwrap().extend(0..7);
+
+    let chunks: Vec<&[u32]> = any_vec.chunks(3).map(|c| c.downcast_ref::<u32>().unwrap()).collect();
+    assert_eq!(chunks, [&[0, 1, 2][..], &[3, 4, 5], &[6]]);
+    let chunks: Vec<&[u32]> = any_vec.chunks(3).rev().map(|c| c.downcast_ref::<u32>().unwrap()).collect();
+    assert_eq!(chunks, [&[6][..], &[3, 4, 5], &[0, 1, 2]]);
+    assert_eq!(any_vec.chunks(3).len(), 3);
+
+    let chunk = any_vec.chunks(3).nth(1).unwrap();
+    assert_eq!(chunk.len(), 3);
+    assert_eq!(*chunk.at(1).downcast_ref::<u32>().unwrap(), 4);
+    assert!(chunk.get(3).is_none());
+    assert!(chunk.downcast_ref::<u64>().is_none());
+    assert_equal(chunk.iter().map(|e| *e.downcast_ref::<u32>().unwrap()), 3..6);
+    assert_eq!(chunk.as_bytes().len(), 3 * size_of::<u32>());
+    assert_eq!(chunk.as_bytes().as_ptr(), any_vec.as_bytes()[3 * size_of::<u32>()..].as_ptr());
+
+    // exact
+    let mut chunks = any_vec.chunks_exact(3);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next_back().unwrap().downcast_ref::<u32>().unwrap(), &[3, 4, 5]);
+    assert_eq!(chunks.remainder().downcast_ref::<u32>().unwrap(), &[6]);
+    assert_eq!(chunks.next().unwrap().downcast_ref::<u32>().unwrap(), &[0, 1, 2]);
+    assert!(chunks.next().is_none());
+
+    // mut
+    for (i, mut chunk) in any_vec.chunks_mut(2).enumerate(){
+        for mut e in chunk.iter_mut(){
+            *e.downcast_mut::<u32>().unwrap() += 10 * i as u32;
+        }
+        chunk.downcast_mut::<u32>().unwrap()[0] += 100;
+    }
+    assert_eq!(
+        any_vec.downcast_ref::<u32>().unwrap().as_slice(),
+        &[100, 1, 112, 13, 124, 25, 136]
+    );
+
+    // empty
+    let any_vec: AnyVec = AnyVec::new::<u32>();
+    assert!(any_vec.chunks(3).next().is_none());
+    assert!(any_vec.chunks_exact(3).remainder().is_empty());
+}
+
+#[test]
+fn windows_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<u32>();
+    any_vec.downcast_mut::<u32>().unwrap().extend(0..4);
+
+    let windows: Vec<&[u32]> = any_vec.windows(2).map(|w| w.downcast_ref::<u32>().unwrap()).collect();
+    assert_eq!(windows, [&[0, 1][..], &[1, 2], &[2, 3]]);
+    let mut windows = any_vec.windows(3);
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows.next_back().unwrap().downcast_ref::<u32>().unwrap(), &[1, 2, 3]);
+    assert_eq!(windows.next().unwrap().downcast_ref::<u32>().unwrap(), &[0, 1, 2]);
+    assert!(windows.next().is_none());
+    assert_eq!(any_vec.windows(5).len(), 0);
+    assert!(any_vec.windows(5).next().is_none());
+}
+
+#[test]
+fn chunks_zst_huge_len_test(){
+    let mut any_vec: AnyVec = AnyVec::with_capacity::<()>(usize::MAX);
+    unsafe{ any_vec.set_len(usize::MAX); }
+
+    let size = usize::MAX / 2 + 1;
+    let mut chunks = any_vec.chunks(size);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next().unwrap().len(), size);
+    assert_eq!(chunks.next().unwrap().len(), usize::MAX - size);
+    assert!(chunks.next().is_none());
+    assert_eq!(any_vec.chunks_mut(size).map(|c| c.len()).last(), Some(usize::MAX - size));
+
+    let mut chunks = any_vec.chunks_exact(size);
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks.next().unwrap().len(), size);
+    assert_eq!(chunks.remainder().len(), usize::MAX - size);
+
+    let mut windows = any_vec.windows(2);
+    assert_eq!(windows.len(), usize::MAX - 1);
+    assert_eq!(windows.next().unwrap().len(), 2);
+    assert_eq!(windows.next_back().unwrap().len(), 2);
+    assert_eq!(any_vec.windows(usize::MAX).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Chunk size must be non-zero!")]
+fn chunks_zero_size_test(){
+    let any_vec: AnyVec = AnyVec::new::<u32>();
+    any_vec.chunks(0);
+}
+
+#[test]
+fn swap_reverse_rotate_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap()
+        .extend((0..5).map(|i| i.to_string()));
+    let strings = |any_vec: &AnyVec| any_vec.downcast_ref::<String>().unwrap().as_slice().to_vec();
+
+    any_vec.swap(0, 4);
+    any_vec.swap(2, 2);
+    assert_eq!(strings(&any_vec), ["4", "1", "2", "3", "0"]);
+
+    any_vec.reverse();
+    assert_eq!(strings(&any_vec), ["0", "3", "2", "1", "4"]);
+
+    any_vec.rotate_left(2);
+    assert_eq!(strings(&any_vec), ["2", "1", "4", "0", "3"]);
+    any_vec.rotate_right(1);
+    assert_eq!(strings(&any_vec), ["3", "2", "1", "4", "0"]);
+    any_vec.rotate_right(5);
+    assert_eq!(strings(&any_vec), ["3", "2", "1", "4", "0"]);
+
+    let mut other: AnyVec = AnyVec::new::<String>();
+    other.downcast_mut::<String>().unwrap()
+        .extend(["a", "b"].map(String::from));
+    any_vec.slice_mut(1..3).swap_with_slice(&mut other.slice_mut(..));
+    assert_eq!(strings(&any_vec), ["3", "a", "b", "4", "0"]);
+    assert_eq!(strings(&other), ["2", "1"]);
+
+    other.swap_with_slice(&mut any_vec.slice_mut(3..));
+    assert_eq!(strings(&other), ["4", "0"]);
+
+    // ZST
+    let mut any_vec: AnyVec = AnyVec::new::<()>();
+    any_vec.downcast_mut::<()>().unwrap().extend([(), ()]);
+    any_vec.reverse();
+    any_vec.rotate_left(1);
+    assert_eq!(any_vec.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Type mismatch!")]
+fn swap_with_slice_type_mismatch_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<u32>();
+    any_vec.downcast_mut::<u32>().unwrap().push(1);
+    any_vec.swap_with_slice(&mut (&mut [1u64][..]).into());
+}
@@ -103,4 +103,34 @@ for _ in 0..REPEATS{
     assert_equal(drain, any_drain);
     assert_equal(vec.iter(), any_vec.iter());
 }
-}
\ No newline at end of file
+}
+
+#[test]
+fn any_vec_sort_fuzzy_test() {
+    use any_vec::traits::Ordered;
+for _ in 0..REPEATS/10{
+    let len = rand::thread_rng().gen_range(0..SIZE/10);
+    let mut any_vec: AnyVec<dyn Ordered> = AnyVec::new::<(u8, usize)>();
+    let mut vec = Vec::new();
+
+    // 1. fill with many equal keys
+    for i in 0..len {
+        let key: u8 = rand::thread_rng().gen_range(0..16);
+        any_vec.push(AnyValueWrapper::new((key, i)));
+        vec.push((key, i));
+    }
+
+    // 2. stable sort by key
+    let mut any_vec2 = any_vec.clone_empty();
+    any_vec2.downcast_mut::<(u8, usize)>().unwrap().extend(vec.iter().copied());
+    vec.sort_by_key(|e| e.0);
+    any_vec2.sort_by_key(|e| e.downcast_ref::<(u8, usize)>().unwrap().0);
+    assert_equal(vec.iter(), any_vec2.downcast_ref::<(u8, usize)>().unwrap());
+
+    // 3. full sort
+    vec.sort();
+    any_vec.sort_unstable();
+    assert!(any_vec.is_sorted());
+    assert_equal(vec.iter(), any_vec.downcast_ref::<(u8, usize)>().unwrap());
+}
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "rayon")]
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use impls::impls;
+use rayon::prelude::*;
+use any_vec::{AnyVec, ParIterMut, ParIterRef};
+use any_vec::any_value::AnyValue;
+use any_vec::element::ElementRef;
+use any_vec::mem::{Heap, Mem, MemBuilder, TryReserveError};
+use any_vec::traits::*;
+
+#[test]
+fn par_iter_test(){
+    let mut any_vec: AnyVec<dyn Send + Sync> = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap()
+        .extend((0..1000).map(|i| i.to_string()));
+
+    let sum: usize = any_vec.par_iter()
+        .map(|e| e.downcast_ref::<String>().unwrap().parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(sum, (0..1000).sum());
+
+    any_vec.par_iter_mut().enumerate().for_each(|(i, mut e)|{
+        e.downcast_mut::<String>().unwrap().push_str(&i.to_string());
+    });
+    assert!(
+        any_vec.downcast_ref::<String>().unwrap().iter().enumerate()
+        .all(|(i, s)| *s == format!("{i}{i}"))
+    );
+}
+
+#[test]
+fn par_iter_bounds_test(){
+    // Sync only
+    let mut any_vec: AnyVec<dyn Sync> = AnyVec::new::<usize>();
+    any_vec.downcast_mut::<usize>().unwrap().extend(0..100);
+    let sum: usize = any_vec.par_iter().map(|e| *e.downcast_ref::<usize>().unwrap()).sum();
+    assert_eq!(sum, (0..100).sum());
+
+    assert!(impls!(ParIterRef<'static, dyn Sync, Heap>: Send));
+    assert!(impls!(ElementRef<'static, dyn Sync, Heap>: Send));
+    assert!(!impls!(ParIterMut<'static, dyn Sync, Heap>: Send));
+    assert!(!impls!(&'static mut AnyVec<dyn Sync>: IntoParallelIterator));
+
+    // Send only
+    assert!(impls!(ParIterMut<'static, dyn Send, Heap>: Send));
+    assert!(!impls!(ParIterRef<'static, dyn Send, Heap>: Send));
+    assert!(!impls!(ElementRef<'static, dyn Send, Heap>: Send));
+    assert!(!impls!(&'static AnyVec<dyn Send>: IntoParallelIterator));
+
+    // Send, but not Sync Mem
+    #[derive(Clone)]
+    struct NotSyncHeap;
+    struct NotSyncMem(<Heap as MemBuilder>::Mem, PhantomData<Cell<()>>);
+    impl MemBuilder for NotSyncHeap{
+        type Mem = NotSyncMem;
+        fn build(&mut self, element_layout: Layout) -> Self::Mem {
+            NotSyncMem(Heap.build(element_layout), PhantomData)
+        }
+    }
+    impl Mem for NotSyncMem{
+        fn as_ptr(&self) -> *const u8 { self.0.as_ptr() }
+        fn as_mut_ptr(&mut self) -> *mut u8 { self.0.as_mut_ptr() }
+        fn element_layout(&self) -> Layout { self.0.element_layout() }
+        fn size(&self) -> usize { self.0.size() }
+        fn try_expand(&mut self, additional: usize) -> Result<(), TryReserveError> {
+            self.0.try_expand(additional)
+        }
+    }
+
+    assert!(impls!(AnyVec<dyn Send, NotSyncHeap>: Send));
+    assert!(!impls!(ParIterMut<'static, dyn Send, NotSyncHeap>: Send));
+    assert!(!impls!(&'static mut AnyVec<dyn Send, NotSyncHeap>: IntoParallelIterator));
+    assert!(!impls!(&'static mut AnyVec<dyn Send, NotSyncHeap>: ParallelDrainRange<usize>));
+}
+
+#[test]
+fn par_drain_test(){
+    let mut any_vec: AnyVec<dyn Send> = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap()
+        .extend((0..1000).map(|i| i.to_string()));
+
+    let drained: Vec<String> = any_vec.par_drain(100..900)
+        .map(|e| e.downcast::<String>().unwrap())
+        .collect();
+    assert_eq!(drained, (100..900).map(|i| i.to_string()).collect::<Vec<_>>());
+    assert!(
+        any_vec.downcast_ref::<String>().unwrap().iter()
+        .eq((0..100).chain(900..1000).map(|i| i.to_string()).collect::<Vec<_>>().iter())
+    );
+
+    // Partially consumed, and not consumed at all.
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct S;
+    impl Drop for S{
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    let mut any_vec: AnyVec<dyn Send> = AnyVec::new::<S>();
+    any_vec.downcast_mut::<S>().unwrap().extend((0..1000).map(|_| S));
+
+    let found = any_vec.par_drain(..500).find_any(|_| true);
+    assert!(found.is_some());
+    drop(found);
+    assert_eq!(any_vec.len(), 500);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 500);
+
+    drop(any_vec.par_drain(100..200));
+    assert_eq!(any_vec.len(), 400);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 600);
+}
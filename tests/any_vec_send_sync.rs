@@ -89,3 +89,14 @@ fn any_vec_heap_send_sync_test() {
     test_negative::<mem::Heap>();
     test_negative::<mem::Stack<123>>();
 }
+
+#[test]
+fn any_value_box_send_sync_test() {
+    use any_vec::any_value::AnyValueBox;
+    use any_vec::traits::Cloneable;
+    assert!(!impls!(AnyValueBox<dyn None>: Send));
+    assert!(!impls!(AnyValueBox<dyn None>: Sync));
+    assert!(impls!(AnyValueBox<dyn Cloneable + Send>: Send));
+    assert!(!impls!(AnyValueBox<dyn Cloneable + Send>: Sync));
+    assert!(impls!(AnyValueBox<dyn Cloneable + Send + Sync>: Send & Sync));
+}
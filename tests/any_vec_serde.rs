@@ -0,0 +1,100 @@
+#![cfg(feature = "serde")]
+
+use serde::de::DeserializeSeed;
+use any_vec::{AnyVec, TypeRegistry};
+use any_vec::traits::Serializable;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+struct Point{
+    x: f32,
+    y: f32
+}
+
+#[test]
+fn serialize_test(){
+    let mut any_vec: AnyVec<dyn Serializable + Send + Sync> = AnyVec::new::<Point>();
+    any_vec.downcast_mut::<Point>().unwrap().push(Point{x: 1.0, y: 2.0});
+    assert_eq!(serde_json::to_string(&any_vec).unwrap(), r#"[{"x":1.0,"y":2.0}]"#);
+
+    // typed
+    let mut any_vec: AnyVec = AnyVec::new::<u32>();
+    any_vec.downcast_mut::<u32>().unwrap().extend([1, 2, 3]);
+    assert_eq!(serde_json::to_string(&any_vec.downcast_ref::<u32>().unwrap()).unwrap(), "[1,2,3]");
+    assert_eq!(serde_json::to_string(&any_vec.downcast_mut::<u32>().unwrap()).unwrap(), "[1,2,3]");
+}
+
+#[test]
+fn type_registry_test(){
+    let mut registry = TypeRegistry::new();
+    registry
+        .register::<Point>("point")
+        .register::<String>("string")
+        .register::<u64>("u64");
+    assert_eq!(registry.name_of(std::any::TypeId::of::<String>()), Some("string"));
+    assert!(registry.new_any_vec("point").unwrap().element_typeid() == std::any::TypeId::of::<Point>());
+    assert!(registry.new_any_vec("f32").is_none());
+
+    let mut points: AnyVec<dyn Serializable> = AnyVec::new::<Point>();
+    points.downcast_mut::<Point>().unwrap().extend([Point{x: 1.0, y: 2.0}, Point{x: 3.0, y: 4.0}]);
+    let mut strings: AnyVec<dyn Serializable> = AnyVec::new::<String>();
+    strings.downcast_mut::<String>().unwrap().push(String::from("Hello"));
+    let empty: AnyVec<dyn Serializable> = AnyVec::new::<u64>();
+    let columns = vec![points, strings, empty];
+
+    // Vec<AnyVec>
+    let json = serde_json::to_string(&registry.tagged(columns.as_slice())).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let loaded = registry.seed::<Vec<AnyVec<dyn Serializable>>>().deserialize(&mut deserializer).unwrap();
+    assert_eq!(loaded.len(), 3);
+    assert_eq!(
+        loaded[0].downcast_ref::<Point>().unwrap().as_slice(),
+        columns[0].downcast_ref::<Point>().unwrap().as_slice()
+    );
+    assert_eq!(loaded[1].downcast_ref::<String>().unwrap().as_slice(), &["Hello"]);
+    assert!(loaded[2].downcast_ref::<u64>().unwrap().is_empty());
+
+    // single AnyVec
+    let json = serde_json::to_string(&registry.tagged(&columns[1])).unwrap();
+    assert_eq!(json, r#"["string",["Hello"]]"#);
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let loaded = registry.seed::<AnyVec<dyn Serializable>>().deserialize(&mut deserializer).unwrap();
+    assert_eq!(loaded.downcast_ref::<String>().unwrap().as_slice(), &["Hello"]);
+
+    // errors
+    let mut unregistered: AnyVec<dyn Serializable> = AnyVec::new::<f32>();
+    unregistered.downcast_mut::<f32>().unwrap().push(1.0);
+    assert!(serde_json::to_string(&registry.tagged(&unregistered)).is_err());
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#"["f32",[1.0]]"#);
+    let result = registry.seed::<AnyVec<dyn Serializable>>().deserialize(&mut deserializer);
+    assert!(result.is_err());
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#"["u64",["a"]]"#);
+    let result = registry.seed::<AnyVec<dyn Serializable>>().deserialize(&mut deserializer);
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Type name already registered!")]
+fn type_registry_duplicate_test(){
+    let mut registry: TypeRegistry = TypeRegistry::new();
+    registry.register::<u32>("int").register::<u64>("int");
+}
+
+#[test]
+fn type_registry_size_hint_test(){
+    use serde::de::value::{Error, SeqDeserializer};
+
+    // Claims huge length, like malicious binary input.
+    struct Lying;
+    impl Iterator for Lying{
+        type Item = u8;
+        fn next(&mut self) -> Option<u8> { None }
+        fn size_hint(&self) -> (usize, Option<usize>) { (usize::MAX / 16, Some(usize::MAX / 16)) }
+    }
+
+    let registry: TypeRegistry = TypeRegistry::new();
+    let deserializer = SeqDeserializer::<_, Error>::new(Lying);
+    let loaded = registry.seed::<Vec<AnyVec<dyn Serializable>>>().deserialize(deserializer).unwrap();
+    assert!(loaded.is_empty());
+}
@@ -89,5 +89,364 @@ fn any_vec_cloneable_zst_test(){
     let s1 = size_of_val(&v1);
     let s2 = size_of_val(&v2);
 
-    assert!(s1 > s2);
-}
\ No newline at end of file
+    // All functions are in shared ElementVTable.
+    assert_eq!(s1, s2);
+}
+
+#[test]
+fn any_vec_cloneable_bulk_test(){
+    use std::cell::Cell;
+    use any_vec::any_value::AnyValueBox;
+
+    thread_local!(static CLONES: Cell<usize> = const { Cell::new(0) });
+    fn clone_count() -> usize {
+        CLONES.with(Cell::get)
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct S(usize);
+    impl Clone for S{
+        fn clone(&self) -> Self {
+            CLONES.with(|c| c.set(c.get() + 1));
+            S(self.0)
+        }
+    }
+
+    let mut any_vec: AnyVec<dyn Cloneable> = AnyVec::new::<S>();
+    any_vec.downcast_mut::<S>().unwrap().extend([S(0), S(1), S(2)]);
+
+    any_vec.extend_from_within(1..);
+    assert_eq!(clone_count(), 2);
+    assert_equal(any_vec.downcast_ref::<S>().unwrap().iter().map(|s| s.0), [0, 1, 2, 1, 2]);
+
+    let other = any_vec.clone();
+    any_vec.extend_from_slice(&other.slice(..2));
+    assert_equal(any_vec.downcast_ref::<S>().unwrap().iter().map(|s| s.0), [0, 1, 2, 1, 2, 0, 1]);
+
+    let value: AnyValueBox<dyn Cloneable> = AnyValueBox::new(S(7));
+    let clones = clone_count();
+    any_vec.resize(20, &value);
+    assert_eq!(clone_count() - clones, 13);
+    assert_eq!(any_vec.len(), 20);
+    assert!(any_vec.downcast_ref::<S>().unwrap().as_slice()[7..].iter().all(|s| *s == S(7)));
+
+    any_vec.resize(2, &value);
+    assert_equal(any_vec.downcast_ref::<S>().unwrap().iter().map(|s| s.0), [0, 1]);
+
+    // Value can be vector element.
+    let mut any_vec: AnyVec<dyn Cloneable> = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap().push(String::from("a"));
+    let other = any_vec.clone();
+    any_vec.resize(4, &*other.at(0));
+    assert_equal(any_vec.downcast_ref::<String>().unwrap().iter().map(String::as_str), ["a"; 4]);
+}
+
+#[test]
+fn any_vec_cloneable_bulk_panic_test(){
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+    use any_vec::any_value::AnyValueBox;
+    use any_vec::mem::Stack;
+
+    thread_local!(static CLONES_LEFT: Cell<usize> = const { Cell::new(usize::MAX) });
+
+    // Rc strong count - number of live elements.
+    struct S(Rc<()>);
+    impl Clone for S{
+        fn clone(&self) -> Self {
+            CLONES_LEFT.with(|c| {
+                if c.get() == 0 { panic!("clone"); }
+                c.set(c.get() - 1);
+            });
+            S(self.0.clone())
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut any_vec: AnyVec<dyn Cloneable, Stack<256>> = AnyVec::new::<S>();
+    any_vec.downcast_mut::<S>().unwrap().extend([S(rc.clone()), S(rc.clone()), S(rc.clone())]);
+
+    CLONES_LEFT.with(|c| c.set(2));
+    assert!(catch_unwind(AssertUnwindSafe(|| any_vec.extend_from_within(..))).is_err());
+    assert_eq!(any_vec.len(), 3);
+    assert_eq!(Rc::strong_count(&rc), 1 + 3);
+
+    CLONES_LEFT.with(|c| c.set(5));
+    let value: AnyValueBox<dyn Cloneable> = AnyValueBox::new(S(rc.clone()));
+    assert!(catch_unwind(AssertUnwindSafe(|| any_vec.resize(10, &value))).is_err());
+    assert_eq!(Rc::strong_count(&rc), 1 + 1 + any_vec.len());
+
+    CLONES_LEFT.with(|c| c.set(usize::MAX));
+    any_vec.resize(10, &value);
+    assert_eq!(Rc::strong_count(&rc), 1 + 1 + 10);
+    drop(any_vec);
+    drop(value);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+#[should_panic(expected = "Type mismatch!")]
+fn any_vec_extend_from_slice_type_mismatch_test(){
+    let mut any_vec: AnyVec<dyn Cloneable> = AnyVec::new::<String>();
+    any_vec.extend_from_slice(&(&[1usize][..]).into());
+}
+
+#[test]
+fn any_vec_debuggable_test(){
+    fn do_test<Traits: ?Sized + Debuggable + Trait>()
+        where String: SatisfyTraits<Traits>
+    {
+        let mut any_vec: AnyVec<Traits> = AnyVec::new::<String>();
+        any_vec.downcast_mut::<String>().unwrap().extend([
+            String::from("0"),
+            String::from("1"),
+        ]);
+
+        let control_vec = vec![String::from("0"), String::from("1")];
+        assert_eq!(format!("{any_vec:?}"), format!("{control_vec:?}"));
+        assert_eq!(format!("{:?}", any_vec.at(1)), format!("{:?}", control_vec[1]));
+        assert_eq!(format!("{:?}", any_vec.at_mut(0)), format!("{:?}", control_vec[0]));
+        assert_eq!(format!("{:?}", any_vec.pop().unwrap()), format!("{:?}", control_vec[1]));
+    }
+
+    do_test::<dyn Debuggable>();
+    do_test::<dyn Debuggable + Sync>();
+    do_test::<dyn Debuggable + Send>();
+    do_test::<dyn Debuggable + Sync + Send>();
+    do_test::<dyn CloneableDebuggable>();
+    do_test::<dyn CloneableDebuggable + Sync>();
+    do_test::<dyn CloneableDebuggable + Send>();
+    do_test::<dyn CloneableDebuggable + Sync + Send>();
+}
+
+#[test]
+fn any_vec_cloneable_debuggable_test(){
+    use any_vec::any_value::AnyValueCloneable;
+
+    let mut any_vec: AnyVec<dyn CloneableDebuggable> = AnyVec::new::<String>();
+    any_vec.downcast_mut::<String>().unwrap().push(String::from("0"));
+
+    let mut cloned = any_vec.clone();
+    cloned.push(any_vec.at(0).lazy_clone());
+    assert_eq!(format!("{cloned:?}"), r#"["0", "0"]"#);
+    assert_eq!(format!("{:?}", cloned.pop().unwrap()), r#""0""#);
+}
+
+#[test]
+fn any_vec_debuggable_raw_parts_test(){
+    let mut any_vec: AnyVec<dyn Debuggable> = AnyVec::new::<usize>();
+    any_vec.downcast_mut::<usize>().unwrap().extend([1, 2, 3]);
+
+    let raw_parts = any_vec.into_raw_parts();
+    let any_vec: AnyVec<dyn Debuggable> = unsafe{ AnyVec::from_raw_parts(raw_parts) };
+    assert_eq!(format!("{any_vec:?}"), "[1, 2, 3]");
+}
+
+#[test]
+fn any_vec_comparable_test(){
+    fn do_test<Traits: ?Sized + Comparable + Trait>()
+        where String: SatisfyTraits<Traits>, usize: SatisfyTraits<Traits>
+    {
+        let mut v1: AnyVec<Traits> = AnyVec::new::<String>();
+        v1.downcast_mut::<String>().unwrap().extend([
+            String::from("0"),
+            String::from("1"),
+        ]);
+        let mut v2: AnyVec<Traits> = AnyVec::new::<String>();
+        v2.downcast_mut::<String>().unwrap().extend([
+            String::from("0"),
+            String::from("1"),
+        ]);
+        assert!(v1 == v2);
+        assert!(v1.at(1) == v2.at(1));
+        assert!(v1.at(0) != v2.at(1));
+        assert!(v1.at_mut(0) == v2.at_mut(0));
+
+        v2.downcast_mut::<String>().unwrap().push(String::from("2"));
+        assert!(v1 != v2);
+
+        v2.pop();
+        *v2.downcast_mut::<String>().unwrap().at_mut(1) = String::from("x");
+        assert!(v1 != v2);
+
+        // Different types
+        let v3: AnyVec<Traits> = AnyVec::new::<usize>();
+        let v4: AnyVec<Traits> = AnyVec::new::<String>();
+        assert!(v3 != v4);
+    }
+
+    do_test::<dyn Comparable>();
+    do_test::<dyn Comparable + Sync>();
+    do_test::<dyn Comparable + Send>();
+    do_test::<dyn Comparable + Sync + Send>();
+}
+
+#[test]
+fn any_vec_comparable_mem_test(){
+    use any_vec::mem::Stack;
+
+    let mut v1: AnyVec<dyn Comparable> = AnyVec::new::<usize>();
+    v1.downcast_mut::<usize>().unwrap().extend([1, 2, 3]);
+
+    let mut v2: AnyVec<dyn Comparable, Stack<64>> = AnyVec::new_in::<usize>(Stack);
+    v2.downcast_mut::<usize>().unwrap().extend([1, 2, 3]);
+
+    assert!(v1 == v2);
+}
+
+#[test]
+fn any_vec_hashable_test(){
+    use std::collections::{HashMap, HashSet};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of(v: &impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut v1: AnyVec<dyn Hashable + Send + Sync> = AnyVec::new::<String>();
+    v1.downcast_mut::<String>().unwrap().extend([
+        String::from("0"),
+        String::from("1"),
+        String::from("0"),
+    ]);
+    let v2 = {
+        let mut v2: AnyVec<dyn Hashable + Send + Sync> = AnyVec::new::<String>();
+        v2.downcast_mut::<String>().unwrap().extend([
+            String::from("0"),
+            String::from("1"),
+            String::from("0"),
+        ]);
+        v2
+    };
+    assert!(v1 == v2);
+    assert_eq!(hash_of(&v1), hash_of(&v2));
+    assert_eq!(hash_of(&v1.at(0)), hash_of(&String::from("0")));
+
+    // Elements as keys
+    let set: HashSet<_> = v1.iter().collect();
+    assert_eq!(set.len(), 2);
+
+    // Vectors as keys
+    let mut map = HashMap::new();
+    map.insert(v1, 1);
+    assert_eq!(map.get(&v2), Some(&1));
+}
+
+#[test]
+fn any_vec_ordered_test(){
+    use any_vec::any_value::AnyValueWrapper;
+
+    let mut v: AnyVec<dyn Ordered + Send + Sync> = AnyVec::new::<String>();
+    assert!(v.min().is_none());
+    assert!(v.max().is_none());
+    assert!(v.is_sorted());
+
+    v.downcast_mut::<String>().unwrap().extend(
+        ["3", "1", "4", "1", "5", "9", "2", "6"].map(String::from)
+    );
+    assert!(!v.is_sorted());
+    assert_eq!(v.min().unwrap().downcast_ref::<String>().unwrap(), "1");
+    assert_eq!(v.max().unwrap().downcast_ref::<String>().unwrap(), "9");
+    assert!(v.at(0) > v.at(1));
+    assert!(v.at(1) == v.at(3));
+
+    v.sort();
+    assert!(v.is_sorted());
+    assert_equal(
+        v.downcast_ref::<String>().unwrap().as_slice(),
+        &["1", "1", "2", "3", "4", "5", "6", "9"].map(String::from)
+    );
+
+    assert_eq!(v.binary_search(&AnyValueWrapper::new(String::from("4"))), Ok(4));
+    assert_eq!(v.binary_search(&AnyValueWrapper::new(String::from("0"))), Err(0));
+    assert_eq!(v.binary_search(&AnyValueWrapper::new(String::from("7"))), Err(7));
+    assert_eq!(v.binary_search(&AnyValueWrapper::new(String::from("99"))), Err(8));
+}
+
+#[test]
+#[should_panic]
+fn any_vec_binary_search_type_mismatch_test(){
+    use any_vec::any_value::AnyValueWrapper;
+
+    let v: AnyVec<dyn Ordered> = AnyVec::new::<String>();
+    let _ = v.binary_search(&AnyValueWrapper::new(0usize));
+}
+
+#[test]
+fn any_vec_custom_marker_test(){
+    use std::fmt::{self, Debug, Formatter};
+    use any_vec::any_value::AnyValueCloneable;
+
+    // Combine several built-in capabilities in one marker.
+    trait CloneDebug{}
+    impl Cloneable for dyn CloneDebug + Send{}
+    impl Debuggable for dyn CloneDebug + Send{}
+
+    unsafe fn clone_fn<T: Clone>(src: *const u8, dst: *mut u8, len: usize){
+        for i in 0..len {
+            (dst as *mut T).add(i).write((*(src as *const T).add(i)).clone());
+        }
+    }
+    unsafe fn debug_fn<T: Debug>(ptr: *const u8, f: &mut Formatter<'_>) -> fmt::Result{
+        (*(ptr as *const T)).fmt(f)
+    }
+
+    impl Trait for dyn CloneDebug + Send{
+        type FnTable = usize;
+    }
+    unsafe impl<T: Clone + Debug + Send> FnTableFor<T> for dyn CloneDebug + Send{
+        const FN_TABLE: usize = size_of::<T>();
+        const CLONE_FN: Option<CloneFn> = Some(clone_fn::<T>);
+        const DEBUG_FN: Option<DebugFn> = Some(debug_fn::<T>);
+    }
+
+    let mut v1: AnyVec<dyn CloneDebug + Send> = AnyVec::new::<String>();
+    v1.downcast_mut::<String>().unwrap().extend([
+        String::from("0"),
+        String::from("1"),
+    ]);
+    let mut tmp = v1.clone_empty();
+    tmp.push(v1.at(0).lazy_clone());
+    v1.append(&mut tmp);
+
+    let v2 = v1.clone();
+    assert_eq!(format!("{v2:?}"), r#"["0", "1", "0"]"#);
+    assert_eq!(*v2.fn_table(), size_of::<String>());
+
+    let raw_parts = v2.into_raw_parts();
+    let v3: AnyVec<dyn CloneDebug + Send> = unsafe{ AnyVec::from_raw_parts(raw_parts) };
+    assert_eq!(format!("{:?}", v3.at(1)), r#""1""#);
+
+    fn is_send(_: &impl Send){}
+    is_send(&v3);
+}
+
+#[test]
+fn any_vec_vtable_test(){
+    use any_vec::ElementVTable;
+    use any_vec::any_value::AnyValueWrapper;
+
+    let v1: AnyVec<dyn Cloneable> = AnyVec::new::<String>();
+    assert_eq!(v1.vtable().layout, ElementVTable::of::<String, dyn Cloneable>().layout);
+
+    let vtable = v1.vtable();
+    assert_eq!(vtable.type_id, std::any::TypeId::of::<String>());
+    assert_eq!((vtable.type_name)(), "alloc::string::String");
+    assert!(vtable.drop.is_some());
+    assert!(vtable.clone.is_some());
+    assert!(vtable.debug.is_none());
+
+    // "Plugin" type, described at runtime.
+    static VTABLE: ElementVTable = ElementVTable::new::<String, dyn Debuggable>();
+    let mut v3: AnyVec<dyn Debuggable> = unsafe{ AnyVec::from_vtable(&VTABLE) };
+    v3.push(AnyValueWrapper::new(String::from("0")));
+    v3.push(AnyValueWrapper::new(String::from("1")));
+    assert_eq!(format!("{v3:?}"), r#"["0", "1"]"#);
+
+    let v4: AnyVec<dyn Debuggable> = unsafe{ AnyVec::from_raw_parts(v3.into_raw_parts()) };
+    assert!(std::ptr::eq(v4.vtable(), &VTABLE));
+}
@@ -259,4 +259,44 @@ fn any_vec_index_test() {
     assert_eq!(vec[..], [1, 10, 100]);
     assert_eq!(vec[1..3], [10, 100]);
 }
- */
\ No newline at end of file
+ */
+
+#[test]
+fn retain_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<usize>();
+    let mut vec = any_vec.downcast_mut::<usize>().unwrap();
+    vec.extend(0..10);
+
+    vec.retain(|i| i % 2 == 0);
+    assert_equal(vec.as_slice().iter().copied(), [0, 2, 4, 6, 8]);
+
+    vec.retain_mut(|i| {
+        *i *= 10;
+        *i != 40
+    });
+    assert_equal(vec.as_slice().iter().copied(), [0, 20, 60, 80]);
+}
+
+#[test]
+fn truncate_test(){
+    let mut any_vec: AnyVec = AnyVec::new::<usize>();
+    let mut vec = any_vec.downcast_mut::<usize>().unwrap();
+    vec.extend(0..10);
+
+    vec.truncate(3);
+    assert_equal(vec.as_slice().iter().copied(), 0..3);
+}
+
+#[test]
+fn try_push_test(){
+    use any_vec::mem::{StackN, TryReserveError};
+
+    let mut any_vec: AnyVec<dyn any_vec::traits::None, StackN<2, 16>> = AnyVec::new_in::<u64>(StackN);
+    let mut vec = any_vec.downcast_mut::<u64>().unwrap();
+    assert!(vec.try_push(1).is_ok());
+    assert!(vec.try_insert(0, 0).is_ok());
+    assert_eq!(vec.try_reserve(1), Err(TryReserveError::CapacityExceeded));
+    assert_eq!(vec.try_push(2).unwrap_err().into_value(), 2);
+    assert_eq!(vec.try_extend([3, 4]).unwrap_err().value, 3);
+    assert_equal(vec.as_slice(), &[0, 1]);
+}